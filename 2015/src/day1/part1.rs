@@ -1,12 +1,9 @@
-use aoc_lib::read_to_char;
 use anyhow::Result;
 
-pub fn solve() -> Result<i32> {
-    let input = read_to_char("input/day1p1.txt")?;
-
+pub fn solve(input: &str) -> Result<i32> {
     let mut floor: i32 = 0;
 
-    for (_i, c) in input.iter().enumerate() {
+    for c in input.chars() {
         match c {
             '(' => floor += 1,
             ')' => floor -= 1,
@@ -15,3 +12,16 @@ pub fn solve() -> Result<i32> {
     }
     Ok(floor)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve("(())").unwrap(), 0);
+        assert_eq!(solve("(()(()(").unwrap(), 3);
+        assert_eq!(solve("))(((((").unwrap(), 3);
+        assert_eq!(solve(")())())").unwrap(), -3);
+    }
+}
@@ -1,13 +1,9 @@
-use aoc_lib::read_to_char;
 use anyhow::Result;
-use std::{usize};
-
-pub fn solve() -> Result<usize> {
-    let input = read_to_char("input/day1p1.txt")?;
 
+pub fn solve(input: &str) -> Result<usize> {
     let mut floor: i32 = 0;
 
-    for (i, c) in input.iter().enumerate() {
+    for (i, c) in input.chars().enumerate() {
         match c {
             '(' => floor += 1,
             ')' => floor -= 1,
@@ -1,2 +1,4 @@
 pub mod part1;
 pub mod part2;
+
+aoc_lib::solution!(Day10, 2015, 10, part1::solve, part2::solve);
@@ -1,10 +1,9 @@
-use aoc_lib::read_to_char;
 use anyhow::Result;
 
-pub fn solve() -> Result<usize> {
-    let input: Vec<char> = read_to_char("input/day10.in")?
-        .into_iter()
-        .filter(|c| c.is_digit(10))
+pub fn solve(input: &str) -> Result<usize> {
+    let input: Vec<char> = input
+        .chars()
+        .filter(|c| c.is_ascii_digit())
         .collect();
     println!("Starting input: {}", input.iter().collect::<String>());
 
@@ -59,15 +58,15 @@ fn process_sequence(input: &[char]) -> Vec<char> {
 #[test]
 fn test_process_sequence() {
     assert_eq!(
-        process_sequence(&vec!['1']).iter().collect::<String>(),
+        process_sequence(&['1']).iter().collect::<String>(),
         "11"
     );
     assert_eq!(
-        process_sequence(&vec!['1', '1']).iter().collect::<String>(),
+        process_sequence(&['1', '1']).iter().collect::<String>(),
         "21"
     );
     assert_eq!(
-        process_sequence(&vec!['2', '1']).iter().collect::<String>(),
+        process_sequence(&['2', '1']).iter().collect::<String>(),
         "1211"
     );
 }
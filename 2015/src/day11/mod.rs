@@ -1,2 +1,4 @@
 pub mod part1;
 pub mod part2;
+
+aoc_lib::solution!(Day11, 2015, 11, part1::solve, part2::solve);
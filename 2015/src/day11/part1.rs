@@ -1,9 +1,7 @@
 use anyhow::Result;
 use std::collections::HashSet;
-use std::fs::read_to_string;
 
-pub fn solve() -> Result<String> {
-    let input = read_to_string("input/day11.in")?;
+pub fn solve(input: &str) -> Result<String> {
     let mut password: Vec<char> = input.trim().chars().collect();
 
     loop {
@@ -18,7 +16,7 @@ pub fn solve() -> Result<String> {
     Ok(result)
 }
 
-fn increment_password(password: &mut Vec<char>) {
+fn increment_password(password: &mut [char]) {
     let mut pos = password.len() - 1;
 
     loop {
@@ -74,7 +72,7 @@ mod tests {
 
     #[test]
     fn test_increment_password() {
-        let mut pw = "xx".chars().collect();
+        let mut pw: Vec<char> = "xx".chars().collect();
         increment_password(&mut pw);
         assert_eq!(pw.iter().collect::<String>(), "xy");
 
@@ -1,15 +1,16 @@
 use anyhow::Result;
 use std::collections::HashSet;
-use std::fs::read_to_string;
 
-pub fn solve() -> Result<String> {
-    let input = read_to_string("input/day11p2.in")?;
+pub fn solve(input: &str) -> Result<String> {
     let mut password: Vec<char> = input.trim().chars().collect();
 
-    loop {
-        increment_password(&mut password);
-        if is_valid_password(&password) {
-            break;
+    // Santa's password expires again right after the one found in part 1
+    for _ in 0..2 {
+        loop {
+            increment_password(&mut password);
+            if is_valid_password(&password) {
+                break;
+            }
         }
     }
 
@@ -18,7 +19,7 @@ pub fn solve() -> Result<String> {
     Ok(result)
 }
 
-fn increment_password(password: &mut Vec<char>) {
+fn increment_password(password: &mut [char]) {
     let mut pos = password.len() - 1;
 
     loop {
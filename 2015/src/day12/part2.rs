@@ -1,8 +1,7 @@
 use anyhow::Result;
-use aoc_lib::read_to_char;
 
-pub fn solve() -> Result<i64> {
-    let chars = read_to_char("input/day12.in")?;
+pub fn solve(input: &str) -> Result<i64> {
+    let chars = input.trim().chars().collect();
     let mut parser = JsonParser::new(chars);
     Ok(parser.parse_and_sum())
 }
@@ -1,2 +1,4 @@
 pub mod part1;
 pub mod part2;
+
+aoc_lib::solution!(Day13, 2015, 13, part1::solve, part2::solve);
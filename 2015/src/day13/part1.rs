@@ -1,9 +1,9 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use std::collections::{HashMap, HashSet};
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day13.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
 
     let mut happiness = HashMap::new();
     let mut people = HashSet::new();
@@ -82,7 +82,7 @@ fn calculate_happiness(
 }
 
 fn permutations(items: &[String]) -> Vec<Vec<String>> {
-    if items.len() < 1 {
+    if items.is_empty() {
         return vec![items.to_vec()];
     }
 
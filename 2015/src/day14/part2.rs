@@ -1,5 +1,5 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
 #[derive(Debug)]
 struct Reindeer {
@@ -39,8 +39,8 @@ impl Reindeer {
     }
 }
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day14.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
     let race_time = 2503;
 
     let mut reindeer: Vec<Reindeer> = Vec::new();
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
 #[derive(Debug)]
 struct Ingredient {
@@ -58,8 +58,8 @@ fn calculate_score(ingredients: &[Ingredient], amounts: &[i32; 4]) -> i32 {
     capacity * durability * flavor * texture
 }
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day15.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
 
     let ingredients: Result<Vec<Ingredient>, _> = lines
         .iter()
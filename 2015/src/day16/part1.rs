@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 use anyhow::{anyhow, Result};
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day16.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
     let mut target: HashMap<String, i32> = HashMap::new();
 
     target.insert("children".to_string(), 3);
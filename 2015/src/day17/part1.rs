@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_one_per_line;
+use aoc_lib::parse_one_per_line;
 
-pub fn solve() -> Result<i32> {
-    let containers: Vec<i32> = read_one_per_line("input/day17.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let containers: Vec<i32> = parse_one_per_line(input)?;
     let target = 150;
 
     let mut count = 0;
@@ -11,9 +11,9 @@ pub fn solve() -> Result<i32> {
     for mask in 1..(1 << n) {
         let mut sum = 0;
 
-        for i in 0..n {
+        for (i, container) in containers.iter().enumerate() {
             if mask & (1 << i) != 0 {
-                sum += containers[i];
+                sum += container;
                 if sum > target {
                     break;
                 }
@@ -1,9 +1,9 @@
 
 use anyhow::Result;
-use aoc_lib::read_one_per_line;
+use aoc_lib::parse_one_per_line;
 
-pub fn solve() -> Result<i32> {
-    let containers: Vec<i32> = read_one_per_line("input/day17.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let containers: Vec<i32> = parse_one_per_line(input)?;
     let target = 150;
     let n = containers.len();
 
@@ -14,9 +14,9 @@ pub fn solve() -> Result<i32> {
         let mut sum = 0;
         let mut container_count = 0;
 
-        for i in 0..n {
+        for (i, container) in containers.iter().enumerate() {
             if mask & (1 << i) != 0 {
-                sum += containers[i];
+                sum += container;
                 container_count += 1;
 
                 if sum > target {
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day18.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
 
     let mut grid: Vec<Vec<bool>> = lines
         .iter()
@@ -36,11 +36,7 @@ fn simulate(grid: &[Vec<bool>], rows: usize, cols: usize) -> Vec<Vec<bool>> {
             let neighbors = count_neighbors(grid, i, j, rows, cols);
             let current = grid[i][j];
 
-            new_grid[i][j] = match (current, neighbors) {
-                (true, 2) | (true, 3) => true,
-                (false, 3) => true,
-                _ => false,
-            };
+            new_grid[i][j] = matches!((current, neighbors), (true, 2) | (true, 3) | (false, 3));
         }
     }
 
@@ -1,9 +1,9 @@
 use std::collections::HashSet;
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day19.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
 
     let (replacements, molecule) = parse_input(&lines)?;
 
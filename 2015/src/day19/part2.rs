@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day19.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
     let (_replacements, molecule) = parse_input(&lines)?;
     let e_count = molecule
         .chars()
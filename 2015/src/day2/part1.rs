@@ -1,4 +1,4 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::Result;
 
 pub struct Prism {
@@ -46,8 +46,8 @@ pub fn parse_line(line: &str) -> Option<Prism> {
     ))
 }
 
-pub fn solve() -> Result<i32> {
-    let input = read_lines("input/day2p1.txt")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let input = parse_lines(input);
 
     let total_area = input
         .iter()
@@ -63,3 +63,13 @@ pub fn solve() -> Result<i32> {
 
     Ok(total_area)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve("2x3x4\n1x1x10\n").unwrap(), 58 + 43);
+    }
+}
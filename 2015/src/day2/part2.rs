@@ -1,5 +1,5 @@
 use crate::day2::part1::{parse_line, Prism};
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::{anyhow, Result};
 
 fn ribbon_length(prism: &Prism) -> i32 {
@@ -12,8 +12,8 @@ fn ribbon_length(prism: &Prism) -> i32 {
     perimeter + volume
 }
 
-pub fn solve() -> Result<i32> {
-    let input = read_lines("input/day2p1.txt")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let input = parse_lines(input);
 
     input
         .iter()
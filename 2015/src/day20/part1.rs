@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day20.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
     let target: i32 = lines[0].trim().parse()?;
 
     let max_house = target / 10;
@@ -1,11 +1,11 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<u32> {
+pub fn solve(input: &str) -> Result<u32> {
     // Hit Points: XX
     // Damage:     YY
     // Armor:      ZZ
-    let lines = read_lines("input/day21.in")?;
+    let lines = parse_lines(input);
     let mut iter = lines.iter();
     let boss_hp: u32 = iter
         .next()
@@ -50,18 +50,16 @@ pub fn solve() -> Result<u32> {
         (102, 0, 5),
     ];
 
-    let rings = vec![
-        (0, 0, 0),
+    let rings = [(0, 0, 0),
         (0, 0, 0),
         (25, 1, 0),
         (50, 2, 0),
         (100, 3, 0),
         (20, 0, 1),
         (40, 0, 2),
-        (80, 0, 3),
-    ];
+        (80, 0, 3)];
 
-    let player_hp = 100;
+    let player_hp: u32 = 100;
     let mut best_cost = u32::MAX;
 
     for &(wc, wd, wa) in &weapons {
@@ -79,9 +77,9 @@ pub fn solve() -> Result<u32> {
                     let dmg_to_player = (boss_dmg.saturating_sub(total_arm)).max(1);
 
                     let turns_to_kill_boss =
-                        (boss_hp + dmg_to_boss - 1) / dmg_to_boss;
+                        boss_hp.div_ceil(dmg_to_boss);
                     let turns_to_kill_player =
-                        (player_hp + dmg_to_player - 1) / dmg_to_player;
+                        player_hp.div_ceil(dmg_to_player);
 
                     if turns_to_kill_boss <= turns_to_kill_player {
                         best_cost = best_cost.min(total_cost);
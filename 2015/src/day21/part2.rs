@@ -1,11 +1,11 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<u32> {
+pub fn solve(input: &str) -> Result<u32> {
     // Hit Points: XX
     // Damage:     YY
     // Armor:      ZZ
-    let lines = read_lines("input/day21.in")?;
+    let lines = parse_lines(input);
     let mut iter = lines.iter();
     let boss_hp: u32 = iter
         .next()
@@ -50,18 +50,16 @@ pub fn solve() -> Result<u32> {
         (102, 0, 5),
     ];
 
-    let rings = vec![
-        (0, 0, 0),
+    let rings = [(0, 0, 0),
         (0, 0, 0),
         (25, 1, 0),
         (50, 2, 0),
         (100, 3, 0),
         (20, 0, 1),
         (40, 0, 2),
-        (80, 0, 3),
-    ];
+        (80, 0, 3)];
 
-    let player_hp = 100;
+    let player_hp: u32 = 100;
     let mut worst_cost = 0;
 
     for &(wc, wd, wa) in &weapons {
@@ -78,8 +76,8 @@ pub fn solve() -> Result<u32> {
                     let dmg_to_boss   = (dmg.saturating_sub(boss_arm)).max(1);
                     let dmg_to_player = (boss_dmg.saturating_sub(arm)).max(1);
 
-                    let t_boss   = (boss_hp   + dmg_to_boss   - 1) / dmg_to_boss;
-                    let t_player = (player_hp + dmg_to_player - 1) / dmg_to_player;
+                    let t_boss   = boss_hp.div_ceil(dmg_to_boss);
+                    let t_player = player_hp.div_ceil(dmg_to_player);
 
                     if t_boss > t_player {
                     worst_cost = worst_cost.max(cost);
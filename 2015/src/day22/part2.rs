@@ -1,4 +1,5 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use aoc_lib::parsing::extract_ints;
 use std::collections::{HashMap, VecDeque};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -175,9 +176,14 @@ impl GameState {
     }
 }
 
-pub fn solve() -> Result<i32> {
-    let boss_hp = 51;
-    let boss_damage = 9;
+pub fn solve(input: &str) -> Result<i32> {
+    // Hit Points: XX
+    // Damage:     YY
+    let stats: Vec<i32> = extract_ints(input);
+    let (&boss_hp, &boss_damage) = stats
+        .first()
+        .zip(stats.get(1))
+        .context("Expected boss hit points and damage")?;
 
     find_min_mana_to_win_hard_mode(boss_hp, boss_damage)
 }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
 #[derive(Debug, Copy, Clone)]
 enum Register {
@@ -96,7 +96,7 @@ impl Computer {
 
 impl Instruction {
     fn from_str(s: &str) -> Result<Self> {
-        let parts: Vec<&str> = s.trim().split_whitespace().collect();
+        let parts: Vec<&str> = s.split_whitespace().collect();
 
         match parts[0] {
             "hlf" => {
@@ -140,8 +140,8 @@ fn parse_registers(s: &str) -> Result<Register> {
     }
 }
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day23.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
 
     let instructions: Result<Vec<Instruction>> = lines
         .iter()
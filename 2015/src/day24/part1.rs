@@ -1,12 +1,12 @@
 use anyhow::Result;
-use aoc_lib::read_one_per_line;
+use aoc_lib::parse_one_per_line;
 use std::collections::HashSet;
 
-pub fn solve() -> Result<u64> {
-    let weights: Vec<u32> = read_one_per_line("input/day24.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let weights: Vec<u32> = parse_one_per_line(input)?;
     let total_weight: u32 = weights.iter().sum();
 
-    if total_weight % 3 != 0 {
+    if !total_weight.is_multiple_of(3) {
         return Err(anyhow::anyhow!("Total weight not divisible by 3"));
     }
 
@@ -96,11 +96,10 @@ fn generate_combination(
         return;
     }
 
-    if current.len() == size {
-        if current_sum == target {
+    if current.len() == size
+        && current_sum == target {
             results.push(current.clone());
         }
-    }
 
     if current.len() + (weights.len() - start) < size {
         return;
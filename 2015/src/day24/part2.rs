@@ -1,16 +1,16 @@
 use anyhow::Result;
-use aoc_lib::read_one_per_line;
+use aoc_lib::parse_one_per_line;
 use std::collections::HashSet;
 
-pub fn solve() -> Result<u64> {
-    solve_for_n_groups(4)
+pub fn solve(input: &str) -> Result<u64> {
+    solve_for_n_groups(input, 4)
 }
 
-fn solve_for_n_groups(num_groups: u32) -> Result<u64> {
-    let weights: Vec<u32> = read_one_per_line("input/day24.in")?;
+fn solve_for_n_groups(input: &str, num_groups: u32) -> Result<u64> {
+    let weights: Vec<u32> = parse_one_per_line(input)?;
     let total_weight: u32 = weights.iter().sum();
 
-    if total_weight % num_groups != 0 {
+    if !total_weight.is_multiple_of(num_groups) {
         return Err(anyhow::anyhow!(
             "Total weight {} is not divisible by {}",
             total_weight,
@@ -1,9 +1,9 @@
 use anyhow::{Context, Result};
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use regex::Regex;
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day25.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
     let input_line = lines.first().context("Input file is empty")?;
 
     let re = Regex::new(r"row (\d+), column (\d+)")?;
@@ -1,5 +1,5 @@
 use anyhow::Result;
 
-pub fn solve() -> Result<String> {
+pub fn solve(_input: &str) -> Result<String> {
     Ok("Merry Christmas!".to_string())
 }
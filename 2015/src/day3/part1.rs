@@ -1,31 +1,20 @@
-use aoc_lib::read_to_char;
-use std::collections::HashSet;
 use anyhow::Result;
+use aoc_lib::grid::{Direction, Point};
+use aoc_lib::SparseGrid;
 
-#[derive(Hash, Clone, PartialEq, Eq)]
-struct Coordinate {
-    x: i32,
-    y: i32,
-}
-
-pub fn solve() -> Result<usize> {
-    let input = read_to_char("input/day3p1.txt")?;
-
-    let mut santa_coordinate = Coordinate { x: 0, y: 0 };
-    let mut visited = HashSet::new();
+pub fn solve(input: &str) -> Result<usize> {
+    let mut santa = Point::ORIGIN;
+    let mut presents = SparseGrid::new();
 
-    visited.insert(santa_coordinate.clone());
+    presents.insert_point(santa, 1);
 
-    for c in input {
-        match c {
-            '^' => santa_coordinate.y += 1,
-            'v' => santa_coordinate.y -= 1,
-            '>' => santa_coordinate.x += 1,
-            '<' => santa_coordinate.x -= 1,
-            _ => continue,
-        }
-        visited.insert(santa_coordinate.clone());
+    for c in input.chars() {
+        let Some(dir) = Direction::from_char(c) else {
+            continue;
+        };
+        santa += dir;
+        *presents.get_point_or_insert_with(santa, || 0) += 1;
     }
 
-    Ok(visited.len())
+    Ok(presents.len())
 }
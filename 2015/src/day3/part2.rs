@@ -1,38 +1,23 @@
-use aoc_lib::read_to_char;
-use std::collections::HashSet;
 use anyhow::Result;
+use aoc_lib::grid::{Direction, Point};
+use aoc_lib::SparseGrid;
 
-#[derive(Hash, Clone, PartialEq, Eq)]
-struct Coordinate {
-    x: i32,
-    y: i32,
-}
-
-pub fn solve() -> Result<usize> {
-    let input = read_to_char("input/day3p1.txt")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let mut santa = Point::ORIGIN;
+    let mut robot = Point::ORIGIN;
+    let mut presents = SparseGrid::new();
 
-    let mut santa_coordinate = Coordinate { x: 0, y: 0 };
-    let mut robot_coordinate = Coordinate { x: 0, y: 0 };
-    let mut visited = HashSet::new();
+    presents.insert_point(Point::ORIGIN, 2);
 
-    visited.insert(santa_coordinate.clone());
+    for (i, c) in input.chars().enumerate() {
+        let coord = if i % 2 == 0 { &mut santa } else { &mut robot };
 
-    for (i, c) in input.into_iter().enumerate() {
-        let coord = if i % 2 == 0 {
-            &mut santa_coordinate
-        } else {
-            &mut robot_coordinate
+        let Some(dir) = Direction::from_char(c) else {
+            continue;
         };
-
-        match c {
-            '^' => coord.y += 1,
-            'v' => coord.y -= 1,
-            '>' => coord.x += 1,
-            '<' => coord.x -= 1,
-            _ => continue,
-        }
-        visited.insert(coord.clone());
+        *coord += dir;
+        *presents.get_point_or_insert_with(*coord, || 0) += 1;
     }
 
-    Ok(visited.len())
+    Ok(presents.len())
 }
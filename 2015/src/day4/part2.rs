@@ -8,8 +8,8 @@ fn calculate_md5(input: &str) -> String {
     format!("{:x}", result)
 }
 
-pub fn solve() -> Result<i32> {
-    let input = "ckczppom";
+pub fn solve(input: &str) -> Result<i32> {
+    let input = input.trim();
     let mut number = 1;
 
     loop {
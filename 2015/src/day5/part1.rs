@@ -1,6 +1,4 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use aoc_lib::parse_lines;
 use anyhow::Result;
 
 pub fn is_nice(s: &str) -> bool {
@@ -27,16 +25,7 @@ fn count_nice_strings(strings: &[String]) -> usize {
     strings.iter().filter(|s| is_nice(s)).count()
 }
 
-pub fn read_lines<T>(filename: T) -> Result<Vec<String>>
-where
-    T: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    let reader = BufReader::new(file);
-    reader.lines().collect::<std::io::Result<Vec<String>>>().map_err(Into::into)
-}
-
-pub fn solve() -> Result<usize> {
-    let lines = read_lines("input/day5p1.txt")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let lines = parse_lines(input);
     Ok(count_nice_strings(&lines))
 }
@@ -1,6 +1,6 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::{anyhow, Result};
-use std::{collections::HashMap, usize};
+use std::collections::HashMap;
 
 enum Instruction {
     TurnOn,
@@ -74,8 +74,8 @@ fn total_brightness(lights: &HashMap<(usize, usize), usize>) -> usize {
     lights.values().sum()
 }
 
-pub fn solve() -> Result<usize> {
-    let input = read_lines("input/day6p1.txt")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let input = parse_lines(input);
     let mut lights: HashMap<(usize, usize), usize> = HashMap::new();
 
     for line in input {
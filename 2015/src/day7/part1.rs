@@ -1,5 +1,5 @@
 use anyhow::Result;
-use aoc_lib::{self, read_lines};
+use aoc_lib::{self, parse_lines};
 use std::collections::HashMap;
 
 enum Input {
@@ -76,7 +76,7 @@ fn evaluate_wire(wire: &str, instructions: &[Instruction], cache: &mut HashMap<S
     let instruction = instructions
         .iter()
         .find(|inst| inst.output == wire)
-        .expect(&format!("No instruction found for wire: {}", wire));
+        .unwrap_or_else(|| panic!("No instruction found for wire: {}", wire));
 
     let result = match &instruction.operation {
         Operation::Assign(input) => {
@@ -104,8 +104,8 @@ fn evaluate_wire(wire: &str, instructions: &[Instruction], cache: &mut HashMap<S
 }
 
 // Main
-pub fn solve() -> Result<u16> {
-    let inputs = read_lines("input/day7.txt")?;
+pub fn solve(input: &str) -> Result<u16> {
+    let inputs = parse_lines(input);
     let instructions: Vec<Instruction> = inputs
         .iter()
         .map(|line| parse_line(line))
@@ -1,9 +1,8 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::Result;
-use std::{usize};
 
-pub fn solve() -> Result<usize> {
-    let input = read_lines("input/day8p1.txt")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let input = parse_lines(input);
 
     let mut original_length = 0;
     let mut encoded_length = 0;
@@ -1,12 +1,9 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::Result;
-use std::{
-    collections::{HashMap, HashSet},
-    usize,
-};
+use std::collections::{HashMap, HashSet};
 
-pub fn solve() -> Result<usize> {
-    let inputs = read_lines("input/day9.in")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let inputs = parse_lines(input);
 
     let mut distances: HashMap<(String, String), usize> = HashMap::new();
     let mut cities: HashSet<String> = HashSet::new();
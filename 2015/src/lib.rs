@@ -0,0 +1,67 @@
+use aoc_lib::Registry;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Returns every solved day of 2015
+pub fn registry() -> Registry {
+    Registry::new(2015)
+        .register::<day1::Day1>()
+        .register::<day2::Day2>()
+        .register::<day3::Day3>()
+        .register::<day4::Day4>()
+        .register::<day5::Day5>()
+        .register::<day6::Day6>()
+        .register::<day7::Day7>()
+        .register::<day8::Day8>()
+        .register::<day9::Day9>()
+        .register::<day10::Day10>()
+        .register::<day11::Day11>()
+        .register::<day12::Day12>()
+        .register::<day13::Day13>()
+        .register::<day14::Day14>()
+        .register::<day15::Day15>()
+        .register::<day16::Day16>()
+        .register::<day17::Day17>()
+        .register::<day18::Day18>()
+        .register::<day19::Day19>()
+        .register::<day20::Day20>()
+        .register::<day21::Day21>()
+        .register::<day22::Day22>()
+        .register::<day23::Day23>()
+        .register::<day24::Day24>()
+        .register::<day25::Day25>()
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_lib::aoc_test!(day1_part1, day = 1, part = 1, input = ")())())", expected = -3);
+    aoc_lib::aoc_test!(day1_part2, day = 1, part = 2, input = "()())", expected = 5);
+    aoc_lib::aoc_test!(day2_part2, day = 2, part = 2, input = "2x3x4\n1x1x10\n", expected = 48);
+    // day 18's example runs 4 steps rather than 100, and the blocks of days 19 to 25
+    // illustrate the rules rather than give an input
+    aoc_lib::aoc_examples!(skip = [18, 19, 20, 21, 22, 24, 25]);
+}
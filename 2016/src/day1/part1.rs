@@ -1,31 +1,34 @@
-use anyhow::Result;
-use aoc_lib::read_lines;
+use anyhow::{bail, Result};
+use aoc_lib::grid::{Direction, Point, Turn};
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<i32> {
-    let line = &read_lines("input/day1.in")?[0];
+pub fn solve(input: &str) -> Result<i64> {
+    let line = &parse_lines(input)[0];
 
-    let mut x: i32 = 0;
-    let mut y: i32 = 0;
-    let mut dir: i32 = 0; // 0: N, 1: E, 2: S, 3: W
+    let mut position = Point::ORIGIN;
+    let mut heading = Direction::North;
 
     for instruction in line.split(", ") {
-        let turn = instruction.chars().next().unwrap();
-        let blocks: i32 = instruction[1..].parse()?;
+        let blocks: i64 = instruction[1..].parse()?;
 
-        match turn {
-            'R' => dir = (dir + 1) % 4,
-            'L' => dir = (dir + 3) % 4,
-            _ => unreachable!(),
-        }
-
-        match dir {
-            0 => y += blocks, // North
-            1 => x += blocks, // East
-            2 => y -= blocks, // South
-            3 => x -= blocks, // West
-            _ => unreachable!(),
-        }
+        let Some(turn) = instruction.chars().next().and_then(Turn::from_char) else {
+            bail!("Invalid instruction: {}", instruction);
+        };
+        heading = heading.turn(turn);
+        position = position.step_n(heading, blocks);
     }
 
-    Ok(x.abs() + y.abs())
+    Ok(position.manhattan(Point::ORIGIN))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve("R2, L3").unwrap(), 5);
+        assert_eq!(solve("R2, R2, R2").unwrap(), 2);
+        assert_eq!(solve("R5, L5, R5, R3").unwrap(), 12);
+    }
 }
@@ -1,40 +1,31 @@
-use anyhow::Result;
-use aoc_lib::read_lines;
+use anyhow::{bail, Result};
+use aoc_lib::grid::{Direction, Point, Turn};
+use aoc_lib::parse_lines;
 use std::collections::HashSet;
 
-pub fn solve() -> Result<i32> {
-    let line = &read_lines("input/day1.in")?[0];
+pub fn solve(input: &str) -> Result<i64> {
+    let line = &parse_lines(input)[0];
 
-    let mut x: i32 = 0;
-    let mut y: i32 = 0;
-    let mut dir: i32 = 0; // 0: N, 1: E, 2: S, 3: W
+    let mut position = Point::ORIGIN;
+    let mut heading = Direction::North;
 
     let mut visited = HashSet::new();
-    visited.insert((x, y));
+    visited.insert(position);
 
     for instruction in line.split(", ") {
-        let turn = instruction.chars().next().unwrap();
-        let blocks: i32 = instruction[1..].parse()?;
+        let blocks: i64 = instruction[1..].parse()?;
 
-        match turn {
-            'R' => dir = (dir + 1) % 4,
-            'L' => dir = (dir + 3) % 4,
-            _ => unreachable!(),
-        }
+        let Some(turn) = instruction.chars().next().and_then(Turn::from_char) else {
+            bail!("Invalid instruction: {}", instruction);
+        };
+        heading = heading.turn(turn);
 
         for _ in 0..blocks {
-            match dir {
-                0 => y += 1, // North
-                1 => x += 1, // East
-                2 => y -= 1, // South
-                3 => x -= 1, // West
-                _ => unreachable!(),
-            }
-
-            if !visited.insert((x, y)) {
-                return Ok(x.abs() + y.abs());
+            position += heading;
+            if !visited.insert(position) {
+                return Ok(position.manhattan(Point::ORIGIN));
             }
         }
     }
-    unreachable!()
+    bail!("No location is visited twice")
 }
@@ -1,15 +1,16 @@
 use anyhow::{Context, Result};
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use std::collections::{HashMap, VecDeque};
 
 #[derive(Clone, Copy)]
 enum Dest {
     Bot(u32),
-    Output(u32),
+    // part 1 only follows chips between bots, so which output doesn't matter
+    Output,
 }
 
-pub fn solve() -> Result<u32> {
-    let lines = read_lines("input/day10.in")?;
+pub fn solve(input: &str) -> Result<u32> {
+    let lines = parse_lines(input);
 
     let mut instructions: HashMap<u32, (Dest, Dest)> = HashMap::new();
     let mut initials: Vec<(u32, u32)> = Vec::new();
@@ -29,12 +30,12 @@ pub fn solve() -> Result<u32> {
             let low_dest = if low_type == "bot" {
                 Dest::Bot(low_id)
             } else {
-                Dest::Output(low_id)
+                Dest::Output
             };
             let high_dest = if high_type == "bot" {
                 Dest::Bot(high_id)
             } else {
-                Dest::Output(high_id)
+                Dest::Output
             };
             instructions.insert(bot_id, (low_dest, high_dest));
         }
@@ -42,7 +43,7 @@ pub fn solve() -> Result<u32> {
 
     let mut bots: HashMap<u32, Vec<u32>> = HashMap::new();
     for (chip, bot) in initials {
-        bots.entry(bot).or_insert(Vec::new()).push(chip);
+        bots.entry(bot).or_default().push(chip);
     }
 
     let mut queue: VecDeque<u32> = VecDeque::new();
@@ -67,24 +68,24 @@ pub fn solve() -> Result<u32> {
 
         match instr.0 {
             Dest::Bot(id) => {
-                let target = bots.entry(id).or_insert(Vec::new());
+                let target = bots.entry(id).or_default();
                 target.push(low);
                 if target.len() == 2 {
                     queue.push_back(id);
                 }
             }
-            Dest::Output(_) => {}
+            Dest::Output => {}
         }
 
         match instr.1 {
             Dest::Bot(id) => {
-                let target = bots.entry(id).or_insert(Vec::new());
+                let target = bots.entry(id).or_default();
                 target.push(high);
                 if target.len() == 2 {
                     queue.push_back(id);
                 }
             }
-            Dest::Output(_) => {}
+            Dest::Output => {}
         }
     }
 
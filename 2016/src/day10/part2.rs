@@ -1,5 +1,5 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use std::collections::{HashMap, VecDeque};
 
 #[derive(Clone, Copy, Debug)]
@@ -13,8 +13,8 @@ struct BotInstr {
     high: Dest,
 }
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day10.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
 
     let mut instructions: HashMap<usize, BotInstr> = HashMap::new();
     let mut initial: Vec<(u32, usize)> = Vec::new();
@@ -50,7 +50,7 @@ pub fn solve() -> Result<i32> {
     let mut queue: VecDeque<usize> = VecDeque::new();
 
     for (val, bot) in initial {
-        let chips = bot_chips.entry(bot).or_insert_with(Vec::new);
+        let chips = bot_chips.entry(bot).or_default();
         chips.push(val);
         if chips.len() == 2 {
             queue.push_back(bot);
@@ -72,27 +72,27 @@ pub fn solve() -> Result<i32> {
 
             match instr.low {
                 Dest::Bot(b) => {
-                    let target_chips = bot_chips.entry(b).or_insert_with(Vec::new);
+                    let target_chips = bot_chips.entry(b).or_default();
                     target_chips.push(low_val);
                     if target_chips.len() == 2 {
                         queue.push_back(b);
                     }
                 }
                 Dest::Output(o) => {
-                    outputs.entry(o).or_insert_with(Vec::new).push(low_val);
+                    outputs.entry(o).or_default().push(low_val);
                 }
             }
 
             match instr.high {
                 Dest::Bot(b) => {
-                    let target_chips = bot_chips.entry(b).or_insert_with(Vec::new);
+                    let target_chips = bot_chips.entry(b).or_default();
                     target_chips.push(high_val);
                     if target_chips.len() == 2 {
                         queue.push_back(b);
                     }
                 }
                 Dest::Output(o) => {
-                    outputs.entry(o).or_insert_with(Vec::new).push(high_val);
+                    outputs.entry(o).or_default().push(high_val);
                 }
             }
         }
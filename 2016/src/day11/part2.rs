@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use std::collections::HashMap;
 use std::collections::{HashSet, VecDeque};
 
@@ -54,8 +54,8 @@ fn valid_floor(pos: &[(u8, u8)], f: u8) -> bool {
     gens == 0 || unprotected == 0
 }
 
-pub fn solve() -> Result<i32> {
-    let lines = read_lines("input/day11.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let lines = parse_lines(input);
 
     let mut element_to_pos: HashMap<String, (Option<u8>, Option<u8>)> = HashMap::new();
 
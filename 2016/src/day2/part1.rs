@@ -1,25 +1,21 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::grid::{Direction, Point};
+use aoc_lib::{parse_lines, Grid};
 
-pub fn solve() -> Result<String> {
-    let lines = read_lines("input/day2.in")?;
-    let mut x: i32 = 1;
-    let mut y: i32 = 1;
+pub fn solve(input: &str) -> Result<String> {
+    let keypad = Grid::parse_chars(&["123", "456", "789"]);
+    let lines = parse_lines(input);
+    let mut position = Point::new(1, 1);
     let mut code = String::new();
 
     for line in lines {
-        for instruction in line.chars() {
-            match instruction {
-                'U' => y = (y - 1).max(0),
-                'D' => y = (y + 1).min(2),
-                'L' => x = (x - 1).max(0),
-                'R' => x = (x + 1).min(2),
-                _ => (),
+        for dir in line.chars().filter_map(Direction::from_char) {
+            if keypad.contains_point(position + dir) {
+                position += dir;
             }
         }
 
-        let digit = 1 + x + y * 3;
-        code.push_str(&digit.to_string());
+        code.push(keypad[position]);
     }
 
     Ok(code)
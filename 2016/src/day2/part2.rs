@@ -1,40 +1,24 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::grid::{Direction, Point};
+use aoc_lib::{parse_lines, Grid};
 
-pub fn solve() -> Result<String> {
-    const KEYPAD: [[char; 5]; 5] = [
-        [' ', ' ', '1', ' ', ' '],
-        [' ', '2', '3', '4', ' '],
-        ['5', '6', '7', '8', '9'],
-        [' ', 'A', 'B', 'C', ' '],
-        [' ', ' ', 'D', ' ', ' '],
-    ];
-    let lines = read_lines("input/day2.in")?;
-    let mut x: i32 = 0;
-    let mut y: i32 = 2;
+pub fn solve(input: &str) -> Result<String> {
+    let keypad = Grid::parse_chars(&["  1  ", " 234 ", "56789", " ABC ", "  D  "]);
+    let lines = parse_lines(input);
+    let mut position = Point::new(0, 2);
     let mut code = String::new();
 
     for line in lines {
-        for instruction in line.chars() {
-            let (mut nx, mut ny) = (x, y);
-            match instruction {
-                'U' => ny -= 1,
-                'D' => ny += 1,
-                'L' => nx -= 1,
-                'R' => nx += 1,
-                _ => (),
-            }
-
-            if (0..=4).contains(&nx)
-                && (0..=4).contains(&ny)
-                && KEYPAD[ny as usize][nx as usize] != ' '
+        for dir in line.chars().filter_map(Direction::from_char) {
+            if keypad
+                .get_point(position + dir)
+                .is_some_and(|&key| key != ' ')
             {
-                x = nx;
-                y = ny;
+                position += dir;
             }
         }
 
-        code.push(KEYPAD[y as usize][x as usize]);
+        code.push(keypad[position]);
     }
 
     Ok(code)
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<usize> {
-    let numbers: Vec<Vec<i32>> = read_lines("input/day3.in")?
+pub fn solve(input: &str) -> Result<usize> {
+    let numbers: Vec<Vec<i32>> = parse_lines(input)
         .iter()
         .map(|line| {
             line.split_whitespace()
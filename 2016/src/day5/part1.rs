@@ -1,8 +1,8 @@
 use anyhow::Result;
 use md5::compute;
 
-pub fn solve() -> Result<String> {
-    let door_id = "ugkcyxxp";
+pub fn solve(input: &str) -> Result<String> {
+    let door_id = input.trim();
     let mut password = String::with_capacity(8);
     let mut index: u64 = 0;
 
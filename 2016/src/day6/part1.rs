@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<String> {
-    let lines = read_lines("input/day6.in")?;
+pub fn solve(input: &str) -> Result<String> {
+    let lines = parse_lines(input);
     if lines.is_empty() {
         return Ok(String::new());
     }
@@ -15,7 +15,7 @@ pub fn solve() -> Result<String> {
 
         for line in &lines {
             let ch = line.as_bytes()[pos] as char;
-            if ch > 'a' && ch <= 'z' {
+            if ch.is_ascii_lowercase() {
                 let idx = (ch as u8 - b'a') as usize;
                 freq[idx] += 1;
             }
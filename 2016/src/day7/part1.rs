@@ -1,5 +1,5 @@
-use anyhow::{Context, Result};
-use aoc_lib::read_lines;
+use anyhow::Result;
+use aoc_lib::parse_lines;
 
 fn has_abba(s: &str) -> bool {
     let chars: Vec<char> = s.chars().collect();
@@ -46,8 +46,8 @@ fn parse_ip(ip: &str) -> (Vec<String>, Vec<String>) {
     (supernets, hypernets)
 }
 
-pub fn solve() -> Result<usize> {
-    let ips = read_lines("input/day7.in").context("Failed to read input file")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let ips = parse_lines(input);
 
     let count = ips
         .iter()
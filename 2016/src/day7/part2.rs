@@ -1,5 +1,5 @@
-use anyhow::{Context, Result};
-use aoc_lib::read_lines;
+use anyhow::Result;
+use aoc_lib::parse_lines;
 
 fn parse_ip(ip: &str) -> (Vec<String>, Vec<String>) {
     let mut supernets = Vec::new();
@@ -53,8 +53,8 @@ fn supports_ssl(supernets: &[String], hypernets: &[String]) -> bool {
     false
 }
 
-pub fn solve() -> Result<usize> {
-    let ips = read_lines("input/day7.in").context("Failed to read input file")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let ips = parse_lines(input);
 
     let count = ips
         .iter()
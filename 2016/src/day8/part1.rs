@@ -1,13 +1,14 @@
 use anyhow::{anyhow, Context, Result};
-use aoc_lib::read_lines;
+use aoc_lib::grid::Grid;
+use aoc_lib::parse_lines;
 
 const WIDTH: usize = 50;
 const HEIGHT: usize = 6;
 
-pub fn solve() -> Result<usize> {
-    let lines = read_lines("input/day8.in")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let lines = parse_lines(input);
 
-    let mut screen: Vec<Vec<u8>> = vec![vec![0; WIDTH]; HEIGHT];
+    let mut screen = Grid::new(WIDTH, HEIGHT, 0u8);
 
     for line in lines {
         let parts: Vec<&str> = line.split_whitespace().collect();
@@ -18,7 +19,7 @@ pub fn solve() -> Result<usize> {
                 let b: usize = ab[1].parse().context("Failed to parse rect height")?;
                 for r in 0..b {
                     for c in 0..a {
-                        screen[r][c] = 1;
+                        screen[(c, r)] = 1;
                     }
                 }
             }
@@ -28,21 +29,15 @@ pub fn solve() -> Result<usize> {
                 let idx: usize = coord[1].parse().context("Failed to parse rotate index")?;
                 let by: usize = parts[4].parse().context("Failed to parse rotate amount")?;
                 if kind == "row" {
-                    let by = by % WIDTH;
-                    screen[idx].rotate_right(by);
+                    screen.rotate_row(idx, by);
                 } else if kind == "column" {
-                    let by = by % HEIGHT;
-                    let mut col: Vec<u8> = (0..HEIGHT).map(|r| screen[r][idx]).collect();
-                    col.rotate_right(by);
-                    for r in 0..HEIGHT {
-                        screen[r][idx] = col[r];
-                    }
+                    screen.rotate_col(idx, by);
                 }
             }
             _ => return Err(anyhow!("Invalid instruction: {}", line)),
         }
     }
 
-    let lit_count: usize = screen.iter().flatten().map(|&p| p as usize).sum();
+    let lit_count: usize = screen.iter().map(|(_, _, &p)| p as usize).sum();
     Ok(lit_count)
 }
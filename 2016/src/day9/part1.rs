@@ -1,8 +1,8 @@
 use anyhow::{Context, Result};
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day9.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
     let input = lines.into_iter().next().context("Empty input")?;
 
     let mut length: u64 = 0;
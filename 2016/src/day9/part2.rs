@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
 fn decompressed_length(input: &str) -> Result<u64> {
     let mut length: u64 = 0;
@@ -53,8 +53,8 @@ fn decompressed_length(input: &str) -> Result<u64> {
     Ok(length)
 }
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day9.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
     let input = lines.into_iter().next().context("Empty input")?;
     decompressed_length(&input)
 }
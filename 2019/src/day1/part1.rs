@@ -1,8 +1,8 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::Result;
 
-pub fn solve() -> Result<i32> {
-    let input = read_lines("input/day1.txt")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let input = parse_lines(input);
     let mut result: i32 = 0;
     for line in input {
         if let Ok(mass) = line.parse::<i32>() {
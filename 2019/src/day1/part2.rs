@@ -1,4 +1,4 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::Result;
 
 fn calculate_fuel(mass: i32) -> i32 {
@@ -11,8 +11,8 @@ fn calculate_fuel(mass: i32) -> i32 {
 }
 
 /// part2
-pub fn solve() -> Result<i32> {
-    let input = read_lines("input/day1.txt")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let input = parse_lines(input);
     let mut result: i32 = 0;
 
     for line in input {
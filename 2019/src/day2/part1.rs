@@ -1,8 +1,8 @@
-use aoc_lib::read_single_line;
-use std::{usize};
+use anyhow::Result;
+use aoc_lib::parse_single_line;
 
-pub fn solve() -> Result<i32> {
-    let input: Vec<i32> = read_single_line("input/day2.txt", ",").unwrap();
+pub fn solve(input: &str) -> Result<i32> {
+    let input: Vec<i32> = parse_single_line(input, ",").unwrap();
 
     let mut program = input.clone();
 
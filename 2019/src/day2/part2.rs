@@ -1,7 +1,6 @@
-use aoc_lib::read_lines;
 use anyhow::Result;
 
 /// part2
-pub fn solve() -> Result<i32> {
+pub fn solve(_input: &str) -> Result<i32> {
     todo!();
 }
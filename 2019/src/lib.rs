@@ -0,0 +1,11 @@
+use aoc_lib::Registry;
+
+pub mod day1;
+pub mod day2;
+
+/// Returns every solved day of 2019
+pub fn registry() -> Registry {
+    Registry::new(2019)
+        .register::<day1::Day1>()
+        .register::<day2::Day2>()
+}
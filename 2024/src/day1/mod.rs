@@ -1 +1,3 @@
 pub mod part1;
+
+aoc_lib::solution!(Day1, 2024, 1, part1::solve);
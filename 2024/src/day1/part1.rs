@@ -1,9 +1,9 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::{Context, Result};
 use std::iter::zip;
 
-pub fn solve() -> Result<i32> {
-    let input: Vec<String> = read_lines("input/day1.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let input: Vec<String> = parse_lines(input);
 
     let mut left_numbers: Vec<i32> = Vec::new();
     let mut right_numbers: Vec<i32> = Vec::new();
@@ -11,7 +11,7 @@ pub fn solve() -> Result<i32> {
     for (line_num, line) in input.iter().enumerate() {
         let numbers: Vec<&str> = line.split_whitespace().collect();
 
-        if let (Some(left), Some(right)) = (numbers.get(0), numbers.get(1)) {
+        if let (Some(left), Some(right)) = (numbers.first(), numbers.get(1)) {
             let left_num = left.parse::<i32>()
                 .with_context(|| format!("Failed to parse left number '{}' on line {}", left, line_num + 1))?;
             let right_num = right.parse::<i32>()
@@ -1 +1,3 @@
 pub mod part1;
+
+aoc_lib::solution!(Day2, 2024, 2, part1::solve);
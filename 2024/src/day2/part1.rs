@@ -1,8 +1,8 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::{Context, Result};
 
-pub fn solve() -> Result<i32> {
-    let input = read_lines("input/day2.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let input = parse_lines(input);
     let mut safe_count = 0;
 
     for (line_num, line) in input.iter().enumerate() {
@@ -53,5 +53,5 @@ fn is_safe(nums: &[i32]) -> bool {
 
 fn is_valid_difference(a: i32, b: i32) -> bool {
     let diff = (b - a).abs();
-    diff >= 1 && diff <= 3
+    (1..=3).contains(&diff)
 }
@@ -1 +1,3 @@
 pub mod part1;
+
+aoc_lib::solution!(Day3, 2024, 3, part1::solve);
@@ -1 +1,3 @@
 pub mod part1;
+
+aoc_lib::solution!(Day4, 2024, 4, part1::solve);
@@ -1,4 +1,4 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::Result;
 
 struct Grid {
@@ -71,8 +71,8 @@ impl Grid {
     }
 }
 
-pub fn solve() -> Result<i32> {
-    let input = read_lines("input/day4.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let input = parse_lines(input);
     let grid = Grid::new(input);
     Ok(grid.count_xmas())
 }
@@ -1 +1,3 @@
 pub mod part1;
+
+aoc_lib::solution!(Day5, 2024, 5, part1::solve);
@@ -1,4 +1,4 @@
-use aoc_lib::read_lines_keep_empty;
+use aoc_lib::parse_lines_keep_empty;
 use anyhow::{anyhow, Context, Result};
 
 struct Rule {
@@ -6,8 +6,8 @@ struct Rule {
     after: i32,
 }
 
-pub fn solve() -> Result<i32> {
-    let input = read_lines_keep_empty("input/day5.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let input = parse_lines_keep_empty(input);
 
     // debug
     //for (i, line) in input.iter().take(20).enumerate() {
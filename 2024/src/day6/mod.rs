@@ -1 +1,3 @@
 pub mod part1;
+
+aoc_lib::solution!(Day6, 2024, 6, part1::solve);
@@ -1,16 +1,9 @@
-use aoc_lib::read_lines;
+use aoc_lib::grid::Direction;
+use aoc_lib::parse_lines;
 use anyhow::Result;
 use core::panic;
 use std::collections::HashSet;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
 #[derive(Debug)]
 struct Guard {
     position: (usize, usize),
@@ -19,32 +12,23 @@ struct Guard {
 
 impl Guard {
     fn turn_right(&mut self) {
-        self.direction = match self.direction {
-            Direction::North => Direction::East,
-            Direction::East => Direction::South,
-            Direction::South => Direction::West,
-            Direction::West => Direction::North,
-        }
+        self.direction = self.direction.turn_right();
     }
 
     fn get_position_ahead(&self) -> Option<(usize, usize)> {
         let (row, col) = self.position;
-        match self.direction {
-            Direction::North => row.checked_sub(1).map(|r| (r, col)),
-            Direction::South => Some((row + 1, col)),
-            Direction::East => Some((row, col + 1)),
-            Direction::West => col.checked_sub(1).map(|c| (row, c)),
-        }
+        let (dx, dy) = self.direction.offset();
+        Some((row.checked_add_signed(dy)?, col.checked_add_signed(dx)?))
     }
 }
 
-pub fn solve() -> Result<i32> {
-    let input = read_lines("input/day6.in")?;
+pub fn solve(input: &str) -> Result<i32> {
+    let input = parse_lines(input);
     let (grid, guard) = parse_input(input);
     Ok(results(guard, &grid))
 }
 
-fn results(mut guard: Guard, grid: &Vec<Vec<char>>) -> i32 {
+fn results(mut guard: Guard, grid: &[Vec<char>]) -> i32 {
     let mut visited_positions = HashSet::new();
     let mut visited_states = HashSet::new();
 
@@ -60,19 +44,15 @@ fn results(mut guard: Guard, grid: &Vec<Vec<char>>) -> i32 {
         let next_pos = guard.get_position_ahead();
 
         match next_pos {
-            Some(pos) => {
-                if is_in_bounds(pos, grid) {
-                    if grid[pos.0][pos.1] == '#' {
-                        guard.turn_right();
-                    } else {
-                        guard.position = pos;
-                        visited_positions.insert(guard.position);
-                    }
+            Some(pos) if is_in_bounds(pos, grid) => {
+                if grid[pos.0][pos.1] == '#' {
+                    guard.turn_right();
                 } else {
-                    break;
+                    guard.position = pos;
+                    visited_positions.insert(guard.position);
                 }
             }
-            None => {
+            _ => {
                 break;
             }
         }
@@ -81,7 +61,7 @@ fn results(mut guard: Guard, grid: &Vec<Vec<char>>) -> i32 {
     visited_positions.len() as i32
 }
 
-fn is_in_bounds(position: (usize, usize), grid: &Vec<Vec<char>>) -> bool {
+fn is_in_bounds(position: (usize, usize), grid: &[Vec<char>]) -> bool {
     let (r, c) = position;
     r < grid.len() && c < grid[0].len()
 }
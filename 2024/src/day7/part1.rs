@@ -1,4 +1,4 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::{Context, Result};
 
 struct Equation {
@@ -37,7 +37,7 @@ fn evaluate(numbers: &[i64], operators: &[bool]) -> i64 {
     let mut result = numbers[0];
 
     for i in 0..operators.len() {
-        if operators[i] == false {
+        if !operators[i] {
             result += numbers[i + 1];
         } else {
             result *= numbers[i + 1];
@@ -47,8 +47,8 @@ fn evaluate(numbers: &[i64], operators: &[bool]) -> i64 {
     result
 }
 
-pub fn solve() -> Result<i64> {
-    let input = read_lines("input/day7.in")?;
+pub fn solve(input: &str) -> Result<i64> {
+    let input = parse_lines(input);
     let mut sum = 0;
 
     for (line_num, line) in input.iter().enumerate() {
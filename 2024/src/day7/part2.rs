@@ -1,4 +1,4 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::{Context, Result};
 
 struct Equation {
@@ -54,8 +54,8 @@ fn evaluate(numbers: &[i64], operators: &[u8]) -> i64 {
     result
 }
 
-pub fn solve() -> Result<i64> {
-    let input = read_lines("input/day7.in")?;
+pub fn solve(input: &str) -> Result<i64> {
+    let input = parse_lines(input);
     let mut sum = 0;
 
     for (line_num, line) in input.iter().enumerate() {
@@ -1 +1,3 @@
 pub mod part1;
+
+aoc_lib::solution!(Day8, 2024, 8, part1::solve);
@@ -1,7 +1,7 @@
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use anyhow::Result;
 
-pub fn solve() -> Result<i64> {
-    let input = read_lines("input/day8.in")?;
+pub fn solve(input: &str) -> Result<i64> {
+    let _input = parse_lines(input);
     Ok(0)
 }
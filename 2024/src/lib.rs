@@ -0,0 +1,34 @@
+use aoc_lib::Registry;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+
+/// Returns every solved day of 2024
+pub fn registry() -> Registry {
+    Registry::new(2024)
+        .register::<day1::Day1>()
+        .register::<day2::Day2>()
+        .register::<day3::Day3>()
+        .register::<day4::Day4>()
+        .register::<day5::Day5>()
+        .register::<day6::Day6>()
+        .register::<day7::Day7>()
+        .register::<day8::Day8>()
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_lib::aoc_test!(
+        day1_part1,
+        day = 1,
+        part = 1,
+        input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+        expected = 11,
+    );
+}
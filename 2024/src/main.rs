@@ -1,74 +1,5 @@
 use anyhow::Result;
-use std::env;
-
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
 
 fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() > 1 {
-        let day = &args[1][3..];
-        run_day(day)?;
-    } else {
-        run_all_days()?;
-    }
-
-    Ok(())
-}
-
-fn run_day(day: &str) -> Result<()> {
-    match day {
-        "1" => {
-            println!("Day 1:");
-            println!("Part 1: {}", day1::part1::solve()?);
-        }
-        "2" => {
-            println!("Day 2:");
-            println!("Part 1: {}", day2::part1::solve()?);
-        }
-        "3" => {
-            println!("Day 3:");
-            println!("Part 1: {}", day3::part1::solve()?);
-        }
-        "4" => {
-            println!("Day 4:");
-            println!("Part 1: {}", day4::part1::solve()?);
-        }
-        "5" => {
-            println!("Day 5:");
-            println!("Part 1: {}", day5::part1::solve()?);
-        }
-        "6" => {
-            println!("Day 6:");
-            println!("Part 1: {}", day6::part1::solve()?);
-        }
-        "7" => {
-            println!("Day 7:");
-            println!("Part 1: {}", day7::part1::solve()?);
-            println!("Part 2: {}", day7::part2::solve()?);
-        }
-        "8" => {
-            println!("Day 8:");
-            println!("Part 1: {}", day8::part1::solve()?);
-        }
-        _ => println!("Day not implemented"),
-    }
-    Ok(())
-}
-
-fn run_all_days() -> Result<()> {
-    for day in 1..=8 {
-        if let Err(e) = run_day(&day.to_string()) {
-            eprintln!("Error running day {}: {}", day, e);
-        }
-        println!();
-    }
-    Ok(())
+    aoc_lib::runner::main(&aoc_2024::registry())
 }
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<usize> {
-    let lines = read_lines("input/day1.in")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let lines = parse_lines(input);
 
     // start at 50, apply rotation, check if resulting position is 0
     let count = lines
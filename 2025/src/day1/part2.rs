@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<i64> {
-    let lines = read_lines("input/day1.in")?;
+pub fn solve(input: &str) -> Result<i64> {
+    let lines = parse_lines(input);
 
     let (_, clicks) = lines.iter().fold((50i64, 0i64), |(pos, clicks), line| {
         let (dir, amt_str) = line.split_at(1);
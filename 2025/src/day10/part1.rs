@@ -1,5 +1,5 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
 fn min_presses(line: &str) -> u32 {
     let target: u64 = line
@@ -33,8 +33,8 @@ fn min_presses(line: &str) -> u32 {
         .unwrap()
 }
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day10.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
     let total: u32 = lines.iter().map(|l| min_presses(l)).sum();
     Ok(total as u64)
 }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
 fn parse_line(line: &str) -> (Vec<Vec<usize>>, Vec<i64>) {
     let buttons: Vec<Vec<usize>> = line
@@ -68,11 +68,12 @@ fn min_presses(line: &str) -> i64 {
         if pivot_row.is_none() { continue; }
         mat.swap(row, pivot_row.unwrap());
         let scale = mat[row][col];
-        for c in 0..=n { mat[row][c] = mat[row][c].div(scale); }
-        for r in 0..m {
-            if r != row && !mat[r][col].is_zero() {
-                let factor = mat[r][col];
-                for c in 0..=n { mat[r][c] = mat[r][c].sub(factor.mul(mat[row][c])); }
+        for cell in mat[row].iter_mut() { *cell = cell.div(scale); }
+        let pivot = mat[row].clone();
+        for (r, other) in mat.iter_mut().enumerate() {
+            if r != row && !other[col].is_zero() {
+                let factor = other[col];
+                for (cell, &p) in other.iter_mut().zip(&pivot) { *cell = cell.sub(factor.mul(p)); }
             }
         }
         pivot_cols.push(col);
@@ -86,16 +87,15 @@ fn min_presses(line: &str) -> i64 {
 
     fn search(
         free_cols: &[usize], idx: usize, vals: &mut Vec<i64>,
-        mat: &[Vec<Rat>], pivots: &[usize], n: usize, rank: usize,
-        max_v: i64, best: &mut i64,
+        mat: &[Vec<Rat>], rank: usize, max_v: i64, best: &mut i64,
     ) {
         if idx == free_cols.len() {
             let mut total: i64 = vals.iter().sum();
             if total >= *best { return; }
-            for r in 0..rank {
-                let mut val = mat[r][n];
+            for row in &mat[..rank] {
+                let mut val = row[row.len() - 1];
                 for (i, &fc) in free_cols.iter().enumerate() {
-                    val = val.sub(mat[r][fc].mul(Rat::new(vals[i], 1)));
+                    val = val.sub(row[fc].mul(Rat::new(vals[i], 1)));
                 }
                 match val.to_i64() {
                     Some(v) if v >= 0 => total += v,
@@ -108,16 +108,16 @@ fn min_presses(line: &str) -> i64 {
         }
         for v in 0..=max_v {
             vals.push(v);
-            search(free_cols, idx + 1, vals, mat, pivots, n, rank, max_v, best);
+            search(free_cols, idx + 1, vals, mat, rank, max_v, best);
             vals.pop();
         }
     }
 
-    search(&free_cols, 0, &mut vec![], &mat, &pivot_cols, n, rank, max_free, &mut best);
+    search(&free_cols, 0, &mut vec![], &mat, rank, max_free, &mut best);
     best
 }
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day10.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
     Ok(lines.iter().map(|l| min_presses(l)).sum::<i64>() as u64)
 }
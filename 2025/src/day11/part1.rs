@@ -1,5 +1,5 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use std::collections::{HashMap, HashSet};
 
 fn count_paths(
@@ -31,8 +31,8 @@ fn count_paths(
     count
 }
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day11.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
     
     let mut graph: HashMap<String, Vec<String>> = HashMap::new();
     
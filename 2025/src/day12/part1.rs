@@ -1,6 +1,6 @@
 use anyhow::Result;
-use aoc_lib::read_lines_keep_empty;
-use std::collections::{BTreeSet, HashSet};
+use aoc_lib::parse_lines_keep_empty;
+use std::collections::HashSet;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 struct Point {
@@ -28,8 +28,8 @@ struct Query {
     counts: Vec<usize>,
 }
 
-pub fn solve() -> Result<usize> {
-    let lines = read_lines_keep_empty("input/day12.in")?;
+pub fn solve(input: &str) -> Result<usize> {
+    let lines = parse_lines_keep_empty(input);
     let (shapes, queries) = parse_input(&lines);
 
     let mut success_count = 0;
@@ -73,7 +73,6 @@ fn parse_input(lines: &[String]) -> (Vec<Shape>, Vec<Query>) {
                 .map(|s| s.parse().unwrap())
                 .collect();
             let counts: Vec<usize> = parts[1]
-                .trim()
                 .split_whitespace()
                 .map(|s| s.parse().unwrap())
                 .collect();
@@ -194,7 +193,7 @@ fn can_fit(shapes: &[Shape], query: &Query) -> bool {
         return false;
     }
 
-    to_place.sort_by(|a, b| b.size.cmp(&a.size));
+    to_place.sort_by_key(|shape| std::cmp::Reverse(shape.size));
 
     let mut grid = vec![false; query.width * query.height];
     backtrack(0, &to_place, &mut grid, query.width, query.height)
@@ -212,12 +211,6 @@ fn backtrack(
     }
 
     let piece = pieces[idx];
-    
-    let start_pos = if idx > 0 && pieces[idx].id == pieces[idx - 1].id {
-        0 
-    } else {
-        0
-    };
 
     let len = grid.len();
 
@@ -226,7 +219,7 @@ fn backtrack(
             continue;
         }
 
-        for pos in start_pos..len {
+        for pos in 0..len {
             let r = pos / width;
             let c = pos % width;
 
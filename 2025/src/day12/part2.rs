@@ -1,9 +1,9 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use std::collections::HashMap;
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day11.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
 
     let mut graph: HashMap<&str, Vec<&str>> = HashMap::new();
 
@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use aoc_lib::read_single_line;
+use aoc_lib::parse_single_line;
 use std::str::FromStr;
 
 struct SearchRange {
@@ -21,8 +21,8 @@ impl FromStr for SearchRange {
     }
 }
 
-pub fn solve() -> Result<u64> {
-    let ranges: Vec<SearchRange> = read_single_line("input/day2.in", ",")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let ranges: Vec<SearchRange> = parse_single_line(input, ",")?;
 
     let total_sum = ranges
         .iter()
@@ -54,5 +54,5 @@ fn is_invalid_id(n: u64) -> bool {
     let k = digits / 2;
     let divisor = 10u64.pow(k) + 1;
 
-    n % divisor == 0
+    n.is_multiple_of(divisor)
 }
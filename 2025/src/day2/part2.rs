@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use aoc_lib::read_single_line;
+use aoc_lib::parse_single_line;
 use std::str::FromStr;
 
 struct SearchRange {
@@ -21,8 +21,8 @@ impl FromStr for SearchRange {
     }
 }
 
-pub fn solve() -> Result<u64> {
-    let ranges: Vec<SearchRange> = read_single_line("input/day2.in", ",")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let ranges: Vec<SearchRange> = parse_single_line(input, ",")?;
 
     let total_sum = ranges
         .into_iter()
@@ -60,7 +60,7 @@ fn is_invalid_id(n: u64) -> bool {
                 }
             }
 
-            if r > 0 && n % r == 0 {
+            if r > 0 && n.is_multiple_of(r) {
                 return true;
             }
         }
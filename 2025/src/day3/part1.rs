@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<u32> {
-    let lines = read_lines("input/day3.in")?;
+pub fn solve(input: &str) -> Result<u32> {
+    let lines = parse_lines(input);
     let mut total_output = 0;
     for line in lines {
         let digits: Vec<u32> = line.chars().filter_map(|c| c.to_digit(10)).collect();
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day3.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
     let mut total_output: u64 = 0;
 
     for line in lines {
@@ -18,9 +18,9 @@ pub fn solve() -> Result<u64> {
 
             let mut max_digit = 0;
             let mut max_pos = start;
-            for i in start..=end {
-                if digits[i] > max_digit {
-                    max_digit = digits[i];
+            for (i, &digit) in digits.iter().enumerate().take(end + 1).skip(start) {
+                if digit > max_digit {
+                    max_digit = digit;
                     max_pos = i;
                 }
             }
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<u32> {
-    let grid: Vec<Vec<char>> = read_lines("input/day4.in")?
+pub fn solve(input: &str) -> Result<u32> {
+    let grid: Vec<Vec<char>> = parse_lines(input)
         .iter()
         .map(|line| line.chars().collect())
         .collect();
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines_keep_empty;
+use aoc_lib::parse_lines_keep_empty;
 
-pub fn solve() -> Result<u32> {
-    let lines = read_lines_keep_empty("input/day5.in")?;
+pub fn solve(input: &str) -> Result<u32> {
+    let lines = parse_lines_keep_empty(input);
     let mut sections = lines.split(|line| line.is_empty());
 
     let ranges: Vec<(u64, u64)> = sections
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day5.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
 
     let mut ranges: Vec<(u64, u64)> = lines
         .iter()
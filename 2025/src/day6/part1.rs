@@ -1,15 +1,15 @@
 use anyhow::Result;
-use aoc_lib::read_lines_keep_empty;
+use aoc_lib::parse_lines_keep_empty;
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines_keep_empty("input/day6.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines_keep_empty(input);
     let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
     let mut col_is_empty = vec![true; width];
 
-    for x in 0..width {
+    for (x, empty) in col_is_empty.iter_mut().enumerate() {
         for line in &lines {
             if x < line.len() && line.as_bytes()[x] != b' ' {
-                col_is_empty[x] = false;
+                *empty = false;
                 break;
             }
         }
@@ -18,8 +18,8 @@ pub fn solve() -> Result<u64> {
     let mut grand_total = 0;
     let mut block_start = None;
 
-    for x in 0..width {
-        let is_content = !col_is_empty[x];
+    for (x, &empty) in col_is_empty.iter().enumerate() {
+        let is_content = !empty;
 
         match (block_start, is_content) {
             (None, true) => {
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day6.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
     let grid: Vec<Vec<char>> = lines.iter().map(|l| l.chars().collect()).collect();
 
     let max_width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
@@ -1,14 +1,13 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 use std::collections::HashSet;
 
-pub fn solve() -> Result<i32> {
-    let grid: Vec<Vec<char>> = read_lines("input/day7.in")?
+pub fn solve(input: &str) -> Result<i32> {
+    let grid: Vec<Vec<char>> = parse_lines(input)
         .iter()
         .map(|l| l.chars().collect())
         .collect();
 
-    let rows = grid.len();
     let cols = grid[0].len();
 
     let start_col = grid[0].iter().position(|&c| c == 'S').unwrap();
@@ -17,11 +16,11 @@ pub fn solve() -> Result<i32> {
     beams.insert(start_col);
     let mut splits = 0;
 
-    for row in 1..rows {
+    for line in grid.iter().skip(1) {
         let mut next_beams: HashSet<usize> = HashSet::new();
 
         for &col in &beams {
-            match grid[row][col] {
+            match line[col] {
                 '^' => {
                     splits += 1;
                     if col > 0 {
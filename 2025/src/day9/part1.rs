@@ -1,8 +1,8 @@
 use anyhow::Result;
-use aoc_lib::read_lines;
+use aoc_lib::parse_lines;
 
-pub fn solve() -> Result<u64> {
-    let lines = read_lines("input/day9.in")?;
+pub fn solve(input: &str) -> Result<u64> {
+    let lines = parse_lines(input);
 
     let points: Vec<(i64, i64)> = lines
         .iter()
@@ -0,0 +1,37 @@
+use aoc_lib::Registry;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+/// Returns every solved day of 2025
+pub fn registry() -> Registry {
+    Registry::new(2025)
+        .register::<day1::Day1>()
+        .register::<day2::Day2>()
+        .register::<day3::Day3>()
+        .register::<day4::Day4>()
+        .register::<day5::Day5>()
+        .register::<day6::Day6>()
+        .register::<day7::Day7>()
+        .register::<day8::Day8>()
+        .register::<day9::Day9>()
+        .register::<day10::Day10>()
+        .register::<day11::Day11>()
+        .register::<day12::Day12>()
+}
+
+#[cfg(test)]
+mod tests {
+    // day 12's example takes half a minute to search in a debug build
+    aoc_lib::aoc_examples!(skip = [12]);
+}
@@ -0,0 +1,209 @@
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// A puzzle answer, whatever type the solver produced it as.
+///
+/// Integers are normalised so that [`Answer::Signed`] only ever holds negative
+/// values, which makes `74i32`, `74u64` and `74usize` the same answer. Strings
+/// that spell an integer exactly, like `"84452"` but not `"084452"`, are read
+/// as that integer too.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// A negative integer
+    Signed(i64),
+    /// A non-negative integer
+    Unsigned(u64),
+    /// A single line of text, e.g. a password
+    Text(String),
+    /// Multi-line ASCII art, e.g. letters drawn on a screen, without a trailing newline
+    Art(String),
+}
+
+impl Answer {
+    /// Whether the answer spans several lines and should be printed on its own
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Art(_))
+    }
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                match u64::try_from(value) {
+                    Ok(value) => Answer::Unsigned(value),
+                    Err(_) => Answer::Signed(value as i64),
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Unsigned(value as u64)
+            }
+        }
+    )*};
+}
+
+from_signed!(i8, i16, i32, i64, isize);
+from_unsigned!(u8, u16, u32, u64, usize);
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        // solvers often end their output with a newline, which stored answers don't keep
+        let trimmed = value.trim_end_matches('\n');
+        if let Some(number) = parse_integer(trimmed) {
+            return number;
+        }
+        if trimmed.contains('\n') {
+            Answer::Art(trimmed.to_string())
+        } else {
+            Answer::Text(trimmed.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::from(value.as_str())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Parses a string that is exactly the display form of an integer
+fn parse_integer(s: &str) -> Option<Answer> {
+    let answer = match s.parse::<u64>() {
+        Ok(value) => Answer::Unsigned(value),
+        Err(_) => Answer::from(s.parse::<i64>().ok()?),
+    };
+    (answer.to_string() == s).then_some(answer)
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Reads back a displayed answer
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Answer::from(s))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => value.fmt(f),
+            Answer::Unsigned(value) => value.fmt(f),
+            Answer::Text(text) | Answer::Art(text) => text.fmt(f),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Signed(value) => serializer.serialize_i64(*value),
+            Answer::Unsigned(value) => serializer.serialize_u64(*value),
+            Answer::Text(text) | Answer::Art(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+struct AnswerVisitor;
+
+impl Visitor<'_> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an integer or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Answer, E> {
+        Ok(value.parse().unwrap_or_else(|e: Infallible| match e {}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_normalise() {
+        assert_eq!(Answer::from(74i32), Answer::from(74u64));
+        assert_eq!(Answer::from(74usize), Answer::Unsigned(74));
+        assert_eq!(Answer::from(-3i32), Answer::Signed(-3));
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+    }
+
+    #[test]
+    fn test_strings() {
+        assert_eq!(
+            Answer::from("hepxxyzz"),
+            Answer::Text("hepxxyzz".to_string())
+        );
+        assert_eq!(
+            Answer::from("#.#\n.#.\n".to_string()),
+            Answer::Art("#.#\n.#.".to_string())
+        );
+        assert!(Answer::from("#.#\n.#.").is_multiline());
+        assert!(!Answer::from(1u8).is_multiline());
+    }
+
+    #[test]
+    fn test_trailing_newline() {
+        assert_eq!(Answer::from("abc\n"), Answer::from("abc"));
+        assert_eq!(Answer::from("1795\n"), Answer::Unsigned(1795));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1795".parse(), Ok(Answer::Unsigned(1795)));
+        assert_eq!("-3".parse(), Ok(Answer::Signed(-3)));
+        assert_eq!("abc".parse(), Ok(Answer::Text("abc".to_string())));
+        assert_eq!("084452".parse(), Ok(Answer::Text("084452".to_string())));
+        assert_eq!(Answer::from("84452".to_string()), Answer::from(84452));
+    }
+
+    #[test]
+    fn test_serde() {
+        let answers = vec![
+            Answer::Signed(-3),
+            Answer::Unsigned(u64::MAX),
+            Answer::Text("heqaabcc".to_string()),
+            Answer::Art("#.\n.#".to_string()),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r##"[-3,18446744073709551615,"heqaabcc","#.\n.#"]"##);
+        let back: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, answers);
+        assert_eq!(
+            serde_json::from_str::<Answer>(r#""74""#).unwrap(),
+            Answer::Unsigned(74)
+        );
+    }
+}
@@ -1,71 +1,486 @@
+use crate::answer::Answer;
+use crate::verify::{Answers, ANSWERS_FILE};
+use anyhow::{bail, Context, Result};
 use colored::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fs;
-use std::time::{Duration, Instant};
+use std::hint::black_box;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-// how many samples to run
+// the most samples to take
 pub const SAMPLE_SIZE: usize = 100;
 
-const BENCH_FILE: &str = "benchmarks.json";
+// untimed runs before sampling, to warm caches and the allocator
+pub const WARMUP_SAMPLES: usize = 3;
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct BenchResult {
+// sampling stops after this long, once at least MIN_SAMPLES are taken
+pub const MAX_BENCH_TIME: Duration = Duration::from_secs(5);
+pub const MIN_SAMPLES: usize = 3;
+
+// fast solutions run several times per sample so each sample takes at least this long,
+// keeping timer resolution out of the results
+pub const MIN_SAMPLE_TIME: Duration = Duration::from_micros(100);
+
+// workspace-wide benchmarks, and the per-year files they replaced
+pub const BENCH_FILE: &str = "benchmarks.json";
+
+// bump when the layout of BENCH_FILE changes, and migrate the old layout in `Benchmarks::load`
+pub const BENCH_VERSION: u32 = 2;
+
+/// Timing statistics over a run of samples, in nanoseconds per call
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    // calls timed together in each sample
+    #[serde(default = "one")]
+    pub iterations: usize,
+    pub mean_ns: f64,
+    // unknown for benchmarks migrated from the per-year files, which only kept the mean
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_ns: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub median_ns: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p95_ns: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stddev_ns: Option<f64>,
+    // samples outside the interquartile range by more than 1.5 times its width
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outliers: Option<usize>,
+    // 95% confidence interval of the mean, unknown with fewer than two samples
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ci95_low_ns: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ci95_high_ns: Option<f64>,
+}
+
+fn one() -> usize {
+    1
+}
+
+impl Stats {
+    /// Summarises a run of samples, each timing `iterations` calls
+    pub fn of(samples: &[Duration], iterations: usize) -> Self {
+        let iterations = iterations.max(1);
+        let mut ns: Vec<f64> = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64 / iterations as f64)
+            .collect();
+        ns.sort_by(f64::total_cmp);
+        let n = ns.len();
+        if n == 0 {
+            return Stats {
+                samples: 0,
+                iterations,
+                mean_ns: 0.0,
+                min_ns: None,
+                median_ns: None,
+                p95_ns: None,
+                stddev_ns: None,
+                outliers: None,
+                ci95_low_ns: None,
+                ci95_high_ns: None,
+            };
+        }
+
+        let mean = ns.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            ns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        let (q1, q3) = (percentile(&ns, 25.0), percentile(&ns, 75.0));
+        let fence = 1.5 * (q3 - q1);
+        let outliers = ns
+            .iter()
+            .filter(|&&x| x < q1 - fence || x > q3 + fence)
+            .count();
+        // the normal approximation, mean ± 1.96 standard errors
+        let margin = 1.96 * variance.sqrt() / (n as f64).sqrt();
+        let ci95 = (n > 1).then_some((mean - margin, mean + margin));
+        Stats {
+            samples: n,
+            iterations,
+            mean_ns: mean,
+            min_ns: Some(ns[0]),
+            median_ns: Some(percentile(&ns, 50.0)),
+            p95_ns: Some(percentile(&ns, 95.0)),
+            stddev_ns: Some(variance.sqrt()),
+            outliers: Some(outliers),
+            ci95_low_ns: ci95.map(|(low, _)| low),
+            ci95_high_ns: ci95.map(|(_, high)| high),
+        }
+    }
+}
+
+// the p-th percentile of sorted values, interpolating between the closest ranks
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[low] + (sorted[high] - sorted[low]) * (rank - low as f64)
+}
+
+/// How a fresh benchmark compares to a stored baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub baseline_ns: f64,
+    pub current_ns: f64,
+}
+
+impl Comparison {
+    /// Compares medians, or means when the baseline has no median
+    pub fn of(baseline: &Stats, current: &Stats) -> Self {
+        match (baseline.median_ns, current.median_ns) {
+            (Some(baseline_ns), Some(current_ns)) => Comparison {
+                baseline_ns,
+                current_ns,
+            },
+            _ => Comparison {
+                baseline_ns: baseline.mean_ns,
+                current_ns: current.mean_ns,
+            },
+        }
+    }
+
+    /// Returns how much slower the current benchmark is, negative when faster
+    pub fn delta_ns(&self) -> f64 {
+        self.current_ns - self.baseline_ns
+    }
+
+    /// Returns the change relative to the baseline, in percent
+    pub fn change_pct(&self) -> f64 {
+        if self.baseline_ns > 0.0 {
+            self.delta_ns() / self.baseline_ns * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// How long and how often to sample a solution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchConfig {
+    pub max_samples: usize,
+    pub max_time: Duration,
+    pub min_sample_time: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            max_samples: SAMPLE_SIZE,
+            max_time: MAX_BENCH_TIME,
+            min_sample_time: MIN_SAMPLE_TIME,
+        }
+    }
+}
+
+/// A stored benchmark of one part
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchRecord {
+    // unknown for legacy records whose output didn't hold the part's answer in answers.json
+    pub answer: Option<Answer>,
+    #[serde(flatten)]
+    pub stats: Stats,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_rev: Option<String>,
+    // seconds since the Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+    // migrated from the per-year files, which only kept the mean and the printed output
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub legacy: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+impl BenchRecord {
+    /// Whether the record is known to time the part's actual solution, so it can
+    /// be compared against and counted in totals
+    pub fn is_verified(&self) -> bool {
+        self.answer.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DayBenchmarks {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<BenchRecord>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<BenchRecord>,
+}
+
+/// Every stored benchmark of the workspace, keyed by year, day and part
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Benchmarks {
+    version: u32,
+    years: BTreeMap<u16, BTreeMap<u8, DayBenchmarks>>,
+}
+
+impl Default for Benchmarks {
+    fn default() -> Self {
+        Benchmarks {
+            version: BENCH_VERSION,
+            years: BTreeMap::new(),
+        }
+    }
+}
+
+// the per-year layout, keyed by day, whose results are the whole printed output of a day
+#[derive(Deserialize)]
+struct LegacyResult {
     duration_ns: f64,
     samples: usize,
     result: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
-pub struct DayBenchmarks {
-    part1: Option<BenchResult>,
-    part2: Option<BenchResult>,
+#[derive(Deserialize)]
+struct LegacyDay {
+    part1: Option<LegacyResult>,
+    part2: Option<LegacyResult>,
 }
 
-pub fn benchmark<F>(f: F, samples: usize) -> (String, f64)
-where
-    F: Fn() -> String,
-{
-    let mut total_duration = Duration::new(0, 0);
-    let result = f();
+impl Benchmarks {
+    /// Reads the benchmarks of the workspace at `root`.
+    ///
+    /// Without a workspace-wide file yet, the per-year files of the old layout are
+    /// migrated instead; saving then writes the new file.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(BENCH_FILE);
+        if !path.exists() {
+            return Self::migrate(root);
+        }
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        let benchmarks: Benchmarks = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse benchmarks in {}", path.display()))?;
+        if benchmarks.version > BENCH_VERSION {
+            bail!(
+                "{} has version {} but only up to {} is supported",
+                path.display(),
+                benchmarks.version,
+                BENCH_VERSION
+            );
+        }
+        Ok(benchmarks)
+    }
 
-    for i in 0..samples {
-        if i % 10 == 0 {
-            print!("\rRunning sample {}/{}", i + 1, samples);
-            std::io::Write::flush(&mut std::io::stdout()).unwrap();
+    /// Converts the per-year `YEAR/benchmarks.json` files of the old layout.
+    ///
+    /// Every record is kept and marked legacy. Old files could hold another day's
+    /// output or parts that were never registered, so a record only keeps its answer
+    /// when it matches the known one in the year's `answers.json`.
+    pub fn migrate(root: &Path) -> Result<Self> {
+        let mut benchmarks = Benchmarks::default();
+        for year in 2015..=2099 {
+            let path = root.join(year.to_string()).join(BENCH_FILE);
+            if !path.exists() {
+                continue;
+            }
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read file: {}", path.display()))?;
+            let days: BTreeMap<u8, LegacyDay> = serde_json::from_str(&contents)
+                .with_context(|| format!("Failed to parse benchmarks in {}", path.display()))?;
+            let answers = Answers::load(&root.join(year.to_string()).join(ANSWERS_FILE))?;
+            for (day, legacy) in days {
+                for (part, result) in [(1, legacy.part1), (2, legacy.part2)] {
+                    let Some(result) = result else {
+                        continue;
+                    };
+                    let answer = legacy_answer(&result.result, part)
+                        .filter(|answer| answers.get(day, part) == Some(answer));
+                    let record = BenchRecord {
+                        answer,
+                        stats: Stats {
+                            samples: result.samples,
+                            iterations: 1,
+                            mean_ns: result.duration_ns,
+                            min_ns: None,
+                            median_ns: None,
+                            p95_ns: None,
+                            stddev_ns: None,
+                            outliers: None,
+                            ci95_low_ns: None,
+                            ci95_high_ns: None,
+                        },
+                        git_rev: None,
+                        timestamp: None,
+                        legacy: true,
+                    };
+                    benchmarks.set(year, day, part, record);
+                }
+            }
         }
-        let start = Instant::now();
-        f();
-        total_duration += start.elapsed();
+        Ok(benchmarks)
     }
-    println!("\rCompleted {} samples", samples);
 
-    let avg_duration = total_duration.as_nanos() as f64 / samples as f64;
-    (result, avg_duration)
+    /// Writes the benchmarks to the workspace-wide file at `root`
+    pub fn save(&self, root: &Path) -> Result<()> {
+        let path = root.join(BENCH_FILE);
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(&path, json + "\n")
+            .with_context(|| format!("Failed to write file: {}", path.display()))
+    }
+
+    /// Returns the stored benchmark of a part, if any
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&BenchRecord> {
+        let day = self.years.get(&year)?.get(&day)?;
+        match part {
+            1 => day.part1.as_ref(),
+            2 => day.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Stores the benchmark of a part, replacing any previous one
+    pub fn set(&mut self, year: u16, day: u8, part: u8, record: BenchRecord) {
+        let benchmarks = self.years.entry(year).or_default().entry(day).or_default();
+        match part {
+            1 => benchmarks.part1 = Some(record),
+            2 => benchmarks.part2 = Some(record),
+            _ => {}
+        }
+    }
+
+    /// Returns every stored benchmark of a year as `(day, part, record)`, in order
+    pub fn year(&self, year: u16) -> Vec<(u8, u8, &BenchRecord)> {
+        let Some(days) = self.years.get(&year) else {
+            return Vec::new();
+        };
+        days.iter()
+            .flat_map(|(&day, benchmarks)| {
+                [(1, &benchmarks.part1), (2, &benchmarks.part2)]
+                    .into_iter()
+                    .filter_map(move |(part, record)| Some((day, part, record.as_ref()?)))
+            })
+            .collect()
+    }
 }
 
-pub fn store_benchmark(day: u32, part: u8, result: String, duration_ns: f64) {
-    let mut benchmarks: HashMap<u32, DayBenchmarks> = fs::read_to_string(BENCH_FILE)
-        .ok()
-        .and_then(|contents| serde_json::from_str(&contents).ok())
-        .unwrap_or_default();
+// picks a part's answer out of output like "Day 1:\nPart 1: 74\nPart 2: 1795"
+fn legacy_answer(result: &str, part: u8) -> Option<Answer> {
+    let label = format!("Part {}:", part);
+    if !result.contains("Part ") {
+        return Some(Answer::from(result));
+    }
+    let mut lines = result.lines().skip_while(|line| !line.starts_with(&label));
+    let first = lines.next()?.strip_prefix(&label)?.trim();
+    if !first.is_empty() {
+        return Some(Answer::from(first));
+    }
+    // multi-line answers start on the line after their label
+    let art: Vec<&str> = lines
+        .take_while(|line| !line.starts_with("Part "))
+        .collect();
+    (!art.is_empty()).then(|| Answer::from(art.join("\n").as_str()))
+}
 
-    let day_bench = benchmarks.entry(day).or_default();
-    let bench_result = BenchResult {
-        duration_ns,
-        samples: SAMPLE_SIZE,
-        result,
+/// Returns the checked-out commit of the repository containing `dir`, marked `-dirty`
+/// when there are uncommitted changes
+pub fn git_revision(dir: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let rev = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    let clean = Command::new("git")
+        .args(["diff", "--quiet", "HEAD"])
+        .current_dir(dir)
+        .status()
+        .is_ok_and(|status| status.success());
+    Some(if clean { rev } else { rev + "-dirty" })
+}
+
+/// Times `f`, returning its first result and the statistics of its samples.
+///
+/// Solutions faster than `min_sample_time` are called repeatedly within each
+/// sample, doubling the count until a sample takes long enough. Sampling stops
+/// at `max_samples`, or after `max_time` once `MIN_SAMPLES` are taken, so slow
+/// solutions don't run for minutes.
+pub fn benchmark<F, T>(f: F, config: &BenchConfig) -> (T, Stats)
+where
+    F: Fn() -> T,
+{
+    let time = |iterations: usize| {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(f());
+        }
+        start.elapsed()
     };
 
-    match part {
-        1 => day_bench.part1 = Some(bench_result),
-        2 => day_bench.part2 = Some(bench_result),
-        _ => return,
+    let start = Instant::now();
+    let result = f();
+    for _ in 1..WARMUP_SAMPLES {
+        if start.elapsed() > config.max_time / 10 {
+            break;
+        }
+        black_box(f());
+    }
+
+    let mut iterations = 1;
+    let mut first = time(iterations);
+    while first < config.min_sample_time && iterations < 1 << 20 {
+        iterations *= 2;
+        first = time(iterations);
     }
 
-    if let Ok(json) = serde_json::to_string_pretty(&benchmarks) {
-        let _ = fs::write(BENCH_FILE, json);
+    let sampling = Instant::now();
+    let mut durations = vec![first];
+    while durations.len() < config.max_samples
+        && (durations.len() < MIN_SAMPLES || sampling.elapsed() < config.max_time)
+    {
+        if durations.len() % 10 == 0 {
+            eprint!(
+                "\rRunning sample {}/{}",
+                durations.len() + 1,
+                config.max_samples
+            );
+        }
+        durations.push(time(iterations));
     }
+    eprintln!(
+        "\rCompleted {} samples of {} iteration{}",
+        durations.len(),
+        iterations,
+        if iterations == 1 { "" } else { "s" }
+    );
+
+    (result, Stats::of(&durations, iterations))
+}
+
+/// Stores a part's benchmark in the workspace at `root`, stamped with the time and git revision
+pub fn store_benchmark(
+    root: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Answer,
+    stats: Stats,
+) -> Result<()> {
+    let mut benchmarks = Benchmarks::load(root)?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .ok();
+    let record = BenchRecord {
+        answer: Some(answer),
+        stats,
+        git_rev: git_revision(root),
+        timestamp,
+        legacy: false,
+    };
+    benchmarks.set(year, day, part, record);
+    benchmarks.save(root)
 }
 
 pub fn format_duration(ns: f64) -> String {
@@ -80,14 +495,211 @@ pub fn format_duration(ns: f64) -> String {
     }
 }
 
-pub fn print_benchmark(_day: u32, part: u8, result: &str, duration_ns: f64) {
-    let duration_str = format_duration(duration_ns);
+// formats a signed duration such as "+1.2ms" or "-300.0ns"
+fn format_delta(ns: f64) -> String {
+    let sign = if ns < 0.0 { '-' } else { '+' };
+    format!("{}{}", sign, format_duration(ns.abs()))
+}
+
+/// Prints how a part compares to its baseline, in red when it is more than
+/// `threshold_pct` slower and in green when it is that much faster
+pub fn print_comparison(baseline: &BenchRecord, comparison: &Comparison, threshold_pct: f64) {
+    let change = format!(
+        "{} ({:+.1}%)",
+        format_delta(comparison.delta_ns()),
+        comparison.change_pct()
+    );
+    let change = if comparison.change_pct() > threshold_pct {
+        change.red().bold()
+    } else if comparison.change_pct() < -threshold_pct {
+        change.bright_green()
+    } else {
+        change.normal()
+    };
+    let rev = match (&baseline.git_rev, baseline.legacy) {
+        (Some(rev), _) => format!(" at {}", rev),
+        (None, true) => " (legacy)".to_string(),
+        (None, false) => String::new(),
+    };
+    println!(
+        "  vs baseline {}{}: {}",
+        format_duration(comparison.baseline_ns),
+        rev,
+        change
+    );
+}
+
+pub fn print_benchmark(part: u8, result: &Answer, stats: &Stats) {
     println!(
         "Part {}: {} ({} @ {} samples)",
         part,
-        result.bright_yellow(),
-        duration_str.bright_blue(),
-        SAMPLE_SIZE
+        crate::runner::inline(result).bright_yellow(),
+        format_duration(stats.mean_ns).bright_blue(),
+        stats.samples
     );
+    if let (Some(min), Some(median), Some(p95), Some(stddev)) =
+        (stats.min_ns, stats.median_ns, stats.p95_ns, stats.stddev_ns)
+    {
+        println!(
+            "  min {}  median {}  p95 {}  stddev {}  outliers {}",
+            format_duration(min),
+            format_duration(median),
+            format_duration(p95),
+            format_duration(stddev),
+            stats.outliers.unwrap_or(0)
+        );
+    }
+    if let (Some(low), Some(high)) = (stats.ci95_low_ns, stats.ci95_high_ns) {
+        println!(
+            "  95% CI of the mean {} to {}",
+            format_duration(low),
+            format_duration(high)
+        );
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_nanos).to_vec();
+        let stats = Stats::of(&samples, 1);
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.mean_ns, 2.5);
+        assert_eq!(stats.min_ns, Some(1.0));
+        assert_eq!(stats.median_ns, Some(2.5));
+        assert!((stats.p95_ns.unwrap() - 3.85).abs() < 1e-9);
+        assert!((stats.stddev_ns.unwrap() - 1.290_994).abs() < 1e-6);
+        assert_eq!(stats.outliers, Some(0));
+        assert_eq!(Stats::of(&[], 1).samples, 0);
+    }
+
+    #[test]
+    fn test_stats_confidence_interval() {
+        // a mean of 20 and a sample stddev of 11.55 over 4 samples, so a margin of 1.96 * 11.55 / 2
+        let samples: Vec<Duration> = [10, 10, 30, 30].map(Duration::from_nanos).to_vec();
+        let stats = Stats::of(&samples, 1);
+        assert!((stats.stddev_ns.unwrap() - 11.547_005).abs() < 1e-6);
+        let margin = 1.96 * 11.547_005 / 2.0;
+        assert!((stats.ci95_low_ns.unwrap() - (20.0 - margin)).abs() < 1e-5);
+        assert!((stats.ci95_high_ns.unwrap() - (20.0 + margin)).abs() < 1e-5);
+
+        let single = Stats::of(&[Duration::from_nanos(7)], 1);
+        assert_eq!((single.ci95_low_ns, single.ci95_high_ns), (None, None));
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert!((json["ci95_high_ns"].as_f64().unwrap() - 31.316_065).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_stats_per_iteration_and_outliers() {
+        let mut samples: Vec<Duration> = (0..20).map(|_| Duration::from_nanos(1000)).collect();
+        samples.push(Duration::from_nanos(50_000));
+        let stats = Stats::of(&samples, 10);
+        assert_eq!(stats.median_ns, Some(100.0));
+        assert_eq!(stats.min_ns, Some(100.0));
+        assert_eq!(stats.outliers, Some(1));
+    }
+
+    #[test]
+    fn test_comparison() {
+        let fast = Stats::of(&[Duration::from_nanos(100)], 1);
+        let slow = Stats::of(&[Duration::from_nanos(150)], 1);
+        let comparison = Comparison::of(&fast, &slow);
+        assert_eq!(comparison.delta_ns(), 50.0);
+        assert_eq!(comparison.change_pct(), 50.0);
+        assert_eq!(Comparison::of(&slow, &fast).delta_ns(), -50.0);
+        assert_eq!(format_delta(-50.0), "-50.0ns");
+
+        let migrated = Stats {
+            median_ns: None,
+            mean_ns: 200.0,
+            ..fast.clone()
+        };
+        assert_eq!(Comparison::of(&migrated, &slow).change_pct(), -25.0);
+    }
+
+    #[test]
+    fn test_benchmark_scales_iterations() {
+        let config = BenchConfig {
+            max_samples: 5,
+            max_time: Duration::from_secs(1),
+            min_sample_time: Duration::from_micros(50),
+        };
+        let (result, stats) = benchmark(|| black_box(6 * 7), &config);
+        assert_eq!(result, 42);
+        assert_eq!(stats.samples, 5);
+        assert!(stats.iterations > 1);
+    }
+
+    #[test]
+    fn test_benchmark_caps_time() {
+        let config = BenchConfig {
+            max_samples: 1000,
+            max_time: Duration::from_millis(20),
+            min_sample_time: Duration::ZERO,
+        };
+        let (_, stats) = benchmark(|| std::thread::sleep(Duration::from_millis(5)), &config);
+        assert_eq!(stats.iterations, 1);
+        assert!((MIN_SAMPLES..10).contains(&stats.samples));
+    }
+
+    #[test]
+    fn test_legacy_answer() {
+        let result = "Day 1:\nPart 1: 74\nPart 2: 1795";
+        assert_eq!(legacy_answer(result, 1), Some(Answer::Unsigned(74)));
+        assert_eq!(legacy_answer(result, 2), Some(Answer::Unsigned(1795)));
+        assert_eq!(legacy_answer("Day 3:\nPart 1: 16", 2), None);
+        assert_eq!(legacy_answer("42", 2), Some(Answer::Unsigned(42)));
+        assert_eq!(
+            legacy_answer("Day 8:\nPart 2:\n#..\n.#.\nPart 3: x", 2),
+            Some(Answer::Art("#..\n.#.".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_migrate_and_round_trip() {
+        let root = env::temp_dir().join(format!("aoc-lib-test-benchmarks-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("2024")).unwrap();
+        fs::write(
+            root.join("2024").join(BENCH_FILE),
+            r#"{"3": {"part1": {"duration_ns": 97.5, "samples": 1000, "result": "Day 3:\nPart 1: 16"}, "part2": null},
+                "1": {"part1": {"duration_ns": 1.0, "samples": 1, "result": "Day 1:\nPart 1: 74\nPart 2: 1795"},
+                      "part2": {"duration_ns": 1.0, "samples": 1, "result": "Day 1:\nPart 1: 74"}}}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("2024").join(ANSWERS_FILE),
+            r#"{"1": {"part1": 241}, "3": {"part1": 16}}"#,
+        )
+        .unwrap();
+
+        let benchmarks = Benchmarks::load(&root).unwrap();
+        let record = benchmarks.get(2024, 3, 1).unwrap();
+        assert_eq!(record.answer, Some(Answer::Unsigned(16)));
+        assert_eq!((record.stats.samples, record.stats.mean_ns), (1000, 97.5));
+        assert!(record.legacy && record.is_verified());
+        assert!(benchmarks.get(2024, 3, 2).is_none());
+        // another day's answer, and a part without one, are kept without an answer
+        for part in 1..=2 {
+            let record = benchmarks.get(2024, 1, part).unwrap();
+            assert!(record.legacy && !record.is_verified());
+        }
+
+        let stats = Stats::of(&[Duration::from_nanos(5)], 1);
+        store_benchmark(&root, 2015, 1, 2, Answer::from(1795), stats.clone()).unwrap();
+        let benchmarks = Benchmarks::load(&root).unwrap();
+        assert_eq!(benchmarks.get(2015, 1, 2).unwrap().stats, stats);
+        assert!(benchmarks.get(2015, 1, 2).unwrap().timestamp.is_some());
+        assert!(!benchmarks.get(2015, 1, 2).unwrap().legacy);
+        assert_eq!(benchmarks.year(2024).len(), 3);
+        assert!(fs::read_to_string(root.join(BENCH_FILE))
+            .unwrap()
+            .starts_with("{\n  \"version\": 2,"));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,4 +1,11 @@
-use std::ops::Index;
+use std::ops::{Index, IndexMut};
+
+mod point;
+mod render;
+mod sparse;
+pub use point::Point;
+pub use render::{Canvas, Color, Renderer};
+pub use sparse::{Bounds, SparseGrid};
 
 /// Direction enum for cardinal directions
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -17,10 +24,11 @@ pub const CARDINAL: [Direction; 4] = [
     Direction::West,
 ];
 
-/// Diagonal direction offsets (dx, dy)
+/// Diagonal direction offsets (dx, dy), matching [`Direction8::NorthWest`],
+/// [`Direction8::SouthWest`], [`Direction8::NorthEast`] and [`Direction8::SouthEast`]
 pub const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
 
-/// All 8 directions (cardinal + diagonal)
+/// All 8 direction offsets (dx, dy), cardinal then diagonal
 pub const ALL_8: [(isize, isize); 8] = [
     (0, -1),  // North
     (0, 1),   // South
@@ -42,6 +50,186 @@ impl Direction {
             Direction::West => (-1, 0),
         }
     }
+
+    /// Returns the direction 90 degrees counter-clockwise
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    /// Returns the direction 90 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// Returns the opposite direction
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// Returns the direction after a turn
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    /// Returns the arrow (`^v<>`) pointing in this direction
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::South => 'v',
+            Direction::East => '>',
+            Direction::West => '<',
+        }
+    }
+
+    /// Parses an arrow (`^v<>`), compass letter (`NSEW`) or move letter (`UDLR`)
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' | 'U' => Some(Direction::North),
+            'v' | 'S' | 'D' => Some(Direction::South),
+            '>' | 'E' | 'R' => Some(Direction::East),
+            '<' | 'W' | 'L' => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+/// A turn instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Turn {
+    /// Parses `L` or `R`
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Turn::Left),
+            'R' => Some(Turn::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Direction enum for all 8 compass directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All 8 directions, clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Returns the (dx, dy) offset for this direction
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Returns the direction `eighths` steps of 45 degrees clockwise (negative for counter-clockwise)
+    pub fn rotate(self, eighths: i32) -> Self {
+        let i = Self::ALL.iter().position(|&dir| dir == self).unwrap() as i32;
+        Self::ALL[(i + eighths).rem_euclid(8) as usize]
+    }
+
+    /// Returns the direction 45 degrees clockwise
+    pub fn rotate_cw(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Returns the direction 45 degrees counter-clockwise
+    pub fn rotate_ccw(self) -> Self {
+        self.rotate(-1)
+    }
+
+    /// Returns the direction 90 degrees counter-clockwise
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    /// Returns the direction 90 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Returns the opposite direction
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Checks if the direction is one of the 4 diagonals
+    pub fn is_diagonal(self) -> bool {
+        let (dx, dy) = self.offset();
+        dx != 0 && dy != 0
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::North => Direction8::North,
+            Direction::South => Direction8::South,
+            Direction::East => Direction8::East,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    fn try_from(dir: Direction8) -> Result<Self, Self::Error> {
+        match dir {
+            Direction8::North => Ok(Direction::North),
+            Direction8::South => Ok(Direction::South),
+            Direction8::East => Ok(Direction::East),
+            Direction8::West => Ok(Direction::West),
+            diagonal => Err(diagonal),
+        }
+    }
 }
 
 /// A 2D grid structure
@@ -81,8 +269,9 @@ impl<T> Grid<T> {
     }
 
     /// Parses a grid from string lines using a converter function
-    pub fn parse<F>(lines: &[String], f: F) -> Self
+    pub fn parse<S, F>(lines: &[S], f: F) -> Self
     where
+        S: AsRef<str>,
         F: Fn(char) -> T,
     {
         if lines.is_empty() {
@@ -94,11 +283,11 @@ impl<T> Grid<T> {
         }
 
         let height = lines.len();
-        let width = lines[0].len();
+        let width = lines[0].as_ref().len();
         let mut data = Vec::with_capacity(width * height);
 
         for line in lines {
-            for ch in line.chars() {
+            for ch in line.as_ref().chars() {
                 data.push(f(ch));
             }
         }
@@ -110,6 +299,15 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Parses a grid from puzzle input, skipping blank lines
+    pub fn parse_str<F>(input: &str, f: F) -> Self
+    where
+        F: Fn(char) -> T,
+    {
+        let lines: Vec<&str> = input.lines().filter(|l| !l.trim().is_empty()).collect();
+        Grid::parse(&lines, f)
+    }
+
     /// Gets a reference to the value at (x, y) if in bounds
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
         if x < self.width && y < self.height {
@@ -199,11 +397,154 @@ impl<T> Grid<T> {
             None
         }
     }
+
+    /// Returns a mutable iterator over all cells with their coordinates
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        let width = self.width;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, v)| (i % width, i / width, v))
+    }
+
+    /// Returns row y as a slice
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns row y as a mutable slice
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns an iterator over the rows, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Returns an iterator over column x, top to bottom
+    pub fn col(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Index out of bounds");
+        // a zero-height grid has no data to start the column at
+        self.data.get(x..).unwrap_or_default().iter().step_by(self.width)
+    }
+
+    /// Returns an iterator over the columns, left to right
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.col(x))
+    }
+
+    /// Reverses rows from..to of column x in place
+    fn reverse_col(&mut self, x: usize, from: usize, to: usize) {
+        let (mut top, mut bottom) = (from, to);
+        while top + 1 < bottom {
+            bottom -= 1;
+            self.data
+                .swap(top * self.width + x, bottom * self.width + x);
+            top += 1;
+        }
+    }
+
+    /// Rotates row y right by `by` cells, wrapping around. Does nothing on an empty grid
+    pub fn rotate_row(&mut self, y: usize, by: usize) {
+        if self.width == 0 || self.height == 0 {
+            return;
+        }
+        let width = self.width;
+        self.row_mut(y).rotate_right(by % width);
+    }
+
+    /// Rotates column x down by `by` cells, wrapping around. Does nothing on an empty grid
+    pub fn rotate_col(&mut self, x: usize, by: usize) {
+        if self.width == 0 || self.height == 0 {
+            return;
+        }
+        assert!(x < self.width, "Index out of bounds");
+        let by = by % self.height;
+        // Rotating right by `by` is three reversals: the whole column, then each part
+        self.reverse_col(x, 0, self.height);
+        self.reverse_col(x, 0, by);
+        self.reverse_col(x, by, self.height);
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Builds a width x height grid taking each cell (x, y) from `source(x, y)` of this grid
+    fn remap<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let (sx, sy) = source(x, y);
+                data.push(self[(sx, sy)].clone());
+            }
+        }
+        Grid {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the grid mirrored along its main diagonal, so (x, y) becomes (y, x)
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Returns the grid rotated 90 degrees clockwise
+    pub fn rotate_90(&self) -> Self {
+        let h = self.height;
+        self.remap(self.height, self.width, |x, y| (y, h - 1 - x))
+    }
+
+    /// Returns the grid rotated 180 degrees
+    pub fn rotate_180(&self) -> Self {
+        let (w, h) = (self.width, self.height);
+        self.remap(w, h, |x, y| (w - 1 - x, h - 1 - y))
+    }
+
+    /// Returns the grid rotated 270 degrees clockwise (90 degrees counter-clockwise)
+    pub fn rotate_270(&self) -> Self {
+        let w = self.width;
+        self.remap(self.height, self.width, |x, y| (w - 1 - y, x))
+    }
+
+    /// Returns the grid mirrored left to right
+    pub fn flip_horizontal(&self) -> Self {
+        let w = self.width;
+        self.remap(self.width, self.height, |x, y| (w - 1 - x, y))
+    }
+
+    /// Returns the grid mirrored top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        let h = self.height;
+        self.remap(self.width, self.height, |x, y| (x, h - 1 - y))
+    }
+
+    /// Returns all 8 rotations and reflections of the grid, starting with the grid itself.
+    ///
+    /// The first four are the rotations by 0, 90, 180 and 270 degrees, the last four the
+    /// same rotations of the horizontally flipped grid. Symmetric grids yield duplicates.
+    pub fn symmetries(&self) -> Vec<Self> {
+        let flipped = self.flip_horizontal();
+        vec![
+            self.clone(),
+            self.rotate_90(),
+            self.rotate_180(),
+            self.rotate_270(),
+            flipped.clone(),
+            flipped.rotate_90(),
+            flipped.rotate_180(),
+            flipped.rotate_270(),
+        ]
+    }
 }
 
 impl Grid<char> {
     /// Parses a character grid
-    pub fn parse_chars(lines: &[String]) -> Grid<char> {
+    pub fn parse_chars<S: AsRef<str>>(lines: &[S]) -> Grid<char> {
         Grid::parse(lines, |c| c)
     }
 }
@@ -217,6 +558,13 @@ impl<T> Index<(usize, usize)> for Grid<T> {
     }
 }
 
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.width && y < self.height, "Index out of bounds");
+        &mut self.data[y * self.width + x]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,6 +598,14 @@ mod tests {
         assert_eq!(grid.get(2, 1), Some(&'f'));
     }
 
+    #[test]
+    fn test_grid_parse_str() {
+        let grid = Grid::parse_str("12\n34\n", |c| c.to_digit(10).unwrap());
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(1, 1)], 4);
+    }
+
     #[test]
     fn test_grid_get_out_of_bounds() {
         let grid = Grid::new(3, 2, 0);
@@ -308,6 +664,58 @@ mod tests {
         assert_eq!(Direction::West.offset(), (-1, 0));
     }
 
+    #[test]
+    fn test_direction_turns() {
+        for dir in CARDINAL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.turn(Turn::Left), dir.turn_left());
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn test_direction_from_char() {
+        for (chars, dir) in [
+            ("^NU", Direction::North),
+            ("vSD", Direction::South),
+            (">ER", Direction::East),
+            ("<WL", Direction::West),
+        ] {
+            assert!(chars.chars().all(|c| Direction::from_char(c) == Some(dir)));
+        }
+        for dir in CARDINAL {
+            assert_eq!(Direction::from_char(dir.arrow()), Some(dir));
+        }
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Turn::from_char('L'), Some(Turn::Left));
+        assert_eq!(Turn::from_char('U'), None);
+    }
+
+    #[test]
+    fn test_direction8() {
+        assert_eq!(Direction8::North.rotate_cw(), Direction8::NorthEast);
+        assert_eq!(Direction8::North.rotate_ccw(), Direction8::NorthWest);
+        assert_eq!(Direction8::NorthEast.turn_right(), Direction8::SouthEast);
+        assert_eq!(Direction8::NorthEast.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.opposite(), Direction8::NorthEast);
+        assert_eq!(Direction8::West.rotate(-9), Direction8::SouthWest);
+        assert!(Direction8::SouthWest.is_diagonal());
+        assert!(!Direction8::South.is_diagonal());
+
+        let mut offsets: Vec<_> = Direction8::ALL.iter().map(|dir| dir.offset()).collect();
+        let mut all_8 = ALL_8.to_vec();
+        offsets.sort();
+        all_8.sort();
+        assert_eq!(offsets, all_8);
+        for dir in CARDINAL {
+            assert_eq!(Direction8::from(dir).offset(), dir.offset());
+            assert_eq!(Direction::try_from(Direction8::from(dir)), Ok(dir));
+        }
+        assert!(Direction::try_from(Direction8::NorthEast).is_err());
+    }
+
     #[test]
     fn test_grid_neighbor() {
         let grid = Grid::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3);
@@ -330,4 +738,93 @@ mod tests {
         assert_eq!(items[0], (0, 0, &1));
         assert_eq!(items[3], (1, 1, &4));
     }
+
+    fn letters() -> Grid<char> {
+        // ab
+        // cd
+        // ef
+        Grid::parse_chars(&["ab", "cd", "ef"])
+    }
+
+    fn lines(grid: &Grid<char>) -> Vec<String> {
+        grid.rows().map(|row| row.iter().collect()).collect()
+    }
+
+    #[test]
+    fn test_grid_index_mut() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(1, 0)] = 5;
+        assert_eq!(grid.get(1, 0), Some(&5));
+    }
+
+    #[test]
+    fn test_grid_iter_mut() {
+        let mut grid = Grid::from_vec(vec![1, 2, 3, 4], 2);
+        for (x, y, v) in grid.iter_mut() {
+            *v += 10 * (x + 2 * y);
+        }
+        assert_eq!(grid, Grid::from_vec(vec![1, 12, 23, 34], 2));
+    }
+
+    #[test]
+    fn test_grid_rows_cols() {
+        let grid = letters();
+        assert_eq!(lines(&grid), ["ab", "cd", "ef"]);
+        let cols: Vec<String> = grid.cols().map(|col| col.collect()).collect();
+        assert_eq!(cols, ["ace", "bdf"]);
+        assert_eq!(grid.row(1), ['c', 'd']);
+    }
+
+    #[test]
+    fn test_grid_rotate_row_col() {
+        let mut grid = Grid::from_vec(vec![1, 2, 3, 4, 5, 6], 3);
+        grid.rotate_row(0, 4);
+        assert_eq!(grid.row(0), [3, 1, 2]);
+        grid.rotate_col(1, 1);
+        assert_eq!(grid, Grid::from_vec(vec![3, 5, 2, 4, 1, 6], 3));
+        grid.rotate_col(1, 2);
+        assert_eq!(grid, Grid::from_vec(vec![3, 5, 2, 4, 1, 6], 3));
+    }
+
+    #[test]
+    fn test_grid_empty_rows_cols() {
+        let mut flat = Grid::new(3, 0, 0);
+        assert_eq!(flat.col(2).count(), 0);
+        assert_eq!(flat.cols().count(), 3);
+        flat.rotate_row(0, 1);
+        flat.rotate_col(1, 1);
+        assert_eq!(flat, Grid::new(3, 0, 0));
+
+        let mut thin = Grid::new(0, 2, 0);
+        assert_eq!(thin.rows().count(), 2);
+        thin.rotate_row(1, 1);
+        thin.rotate_col(0, 1);
+        assert_eq!(thin, Grid::new(0, 2, 0));
+    }
+
+    #[test]
+    fn test_grid_transforms() {
+        let grid = letters();
+        assert_eq!(lines(&grid.transpose()), ["ace", "bdf"]);
+        assert_eq!(lines(&grid.rotate_90()), ["eca", "fdb"]);
+        assert_eq!(lines(&grid.rotate_180()), ["fe", "dc", "ba"]);
+        assert_eq!(lines(&grid.rotate_270()), ["bdf", "ace"]);
+        assert_eq!(lines(&grid.flip_horizontal()), ["ba", "dc", "fe"]);
+        assert_eq!(lines(&grid.flip_vertical()), ["ef", "cd", "ab"]);
+        assert_eq!(grid.rotate_90().rotate_270(), grid);
+    }
+
+    #[test]
+    fn test_grid_symmetries() {
+        let grid = letters();
+        let symmetries = grid.symmetries();
+        assert_eq!(symmetries.len(), 8);
+        assert_eq!(symmetries[0], grid);
+        assert!(symmetries.contains(&grid.transpose()));
+        assert!(symmetries.contains(&grid.flip_vertical()));
+        for (i, symmetry) in symmetries.iter().enumerate() {
+            assert!(!symmetries[i + 1..].contains(symmetry));
+        }
+        assert_eq!(Grid::new(2, 2, '#').symmetries().len(), 8);
+    }
 }
@@ -0,0 +1,332 @@
+use super::{Direction, Direction8, Grid, SparseGrid, CARDINAL};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+/// A signed 2D coordinate or vector.
+///
+/// Follows [`Grid`]: x grows east and y grows south, so north is (0, -1).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    /// The point (0, 0)
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    /// Creates a point from its coordinates
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Returns the point of flat index `i` in a grid of the given width
+    pub fn from_index(i: usize, width: usize) -> Self {
+        Point::new((i % width) as i64, (i / width) as i64)
+    }
+
+    /// Returns the flat index of the point in a grid of the given size, if inside it
+    pub fn to_index(self, width: usize, height: usize) -> Option<usize> {
+        let (x, y) = self.to_coords()?;
+        (x < width && y < height).then_some(y * width + x)
+    }
+
+    /// Returns the point as unsigned grid coordinates, if neither is negative
+    pub fn to_coords(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+
+    /// Returns the Manhattan (taxicab) distance to another point
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Returns the Chebyshev (king move) distance to another point
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Returns the neighboring point in a direction
+    pub fn step(self, dir: Direction) -> Self {
+        self + Point::from(dir)
+    }
+
+    /// Returns the point `n` steps away in a direction
+    pub fn step_n(self, dir: Direction, n: i64) -> Self {
+        self + Point::from(dir) * n
+    }
+
+    /// Returns the vector turned 90 degrees counter-clockwise, e.g. north to west
+    pub fn turn_left(self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+
+    /// Returns the vector turned 90 degrees clockwise, e.g. north to east
+    pub fn turn_right(self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Returns the vector pointing the opposite way
+    pub fn turn_around(self) -> Self {
+        -self
+    }
+
+    /// Returns the 4 cardinal neighbors
+    pub fn neighbors4(self) -> [Point; 4] {
+        CARDINAL.map(|dir| self.step(dir))
+    }
+
+    /// Returns all 8 neighbors, clockwise from north
+    pub fn neighbors8(self) -> [Point; 8] {
+        Direction8::ALL.map(|dir| self + dir)
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(dir: Direction) -> Self {
+        let (dx, dy) = dir.offset();
+        Point::new(dx as i64, dy as i64)
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(dir: Direction8) -> Self {
+        let (dx, dy) = dir.offset();
+        Point::new(dx as i64, dy as i64)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+impl From<Point> for (i64, i64) {
+    fn from(p: Point) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, dir: Direction) -> Point {
+        self.step(dir)
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Point;
+
+    fn add(self, dir: Direction8) -> Point {
+        self + Point::from(dir)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+impl AddAssign<Direction8> for Point {
+    fn add_assign(&mut self, dir: Direction8) {
+        *self = *self + dir;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, n: i64) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Gets a reference to the value at a point if in bounds
+    pub fn get_point(&self, p: Point) -> Option<&T> {
+        let (x, y) = p.to_coords()?;
+        self.get(x, y)
+    }
+
+    /// Gets a mutable reference to the value at a point if in bounds
+    pub fn get_point_mut(&mut self, p: Point) -> Option<&mut T> {
+        let (x, y) = p.to_coords()?;
+        self.get_mut(x, y)
+    }
+
+    /// Checks if a point is within bounds
+    pub fn contains_point(&self, p: Point) -> bool {
+        p.to_coords().is_some_and(|(x, y)| self.contains(x, y))
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Stores a value at a point, returning the value it replaces
+    pub fn insert_point(&mut self, p: Point, value: T) -> Option<T> {
+        self.insert(p.x, p.y, value)
+    }
+
+    /// Removes the value at a point, shrinking the bounds if needed
+    pub fn remove_point(&mut self, p: Point) -> Option<T> {
+        self.remove(p.x, p.y)
+    }
+
+    /// Gets a reference to the value at a point if occupied
+    pub fn get_point(&self, p: Point) -> Option<&T> {
+        self.get(p.x, p.y)
+    }
+
+    /// Gets a mutable reference to the value at a point if occupied
+    pub fn get_point_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.get_mut(p.x, p.y)
+    }
+
+    /// Gets a mutable reference to the value at a point, inserting `f()` if unoccupied
+    pub fn get_point_or_insert_with<F>(&mut self, p: Point, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        self.get_or_insert_with(p.x, p.y, f)
+    }
+
+    /// Checks if a point holds a value
+    pub fn contains_point(&self, p: Point) -> bool {
+        self.contains(p.x, p.y)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get_point(p).expect("Index out of bounds")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        self.get_point_mut(p).expect("Index out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let p = Point::new(3, -2);
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(p * 3, Point::new(9, -6));
+        assert_eq!(-p, Point::new(-3, 2));
+        let mut q = Point::ORIGIN;
+        q += p;
+        q -= Point::new(0, 1);
+        assert_eq!(q, Point::new(3, -3));
+        q += Direction::West;
+        q += Direction8::SouthEast;
+        assert_eq!(q, Point::new(3, -2));
+    }
+
+    #[test]
+    fn test_point_distances() {
+        let p = Point::new(3, -2);
+        assert_eq!(p.manhattan(Point::ORIGIN), 5);
+        assert_eq!(p.chebyshev(Point::ORIGIN), 3);
+        assert_eq!(Point::new(-1, 4).manhattan(p), 10);
+    }
+
+    #[test]
+    fn test_point_step_and_turn() {
+        let north = Point::from(Direction::North);
+        assert_eq!(north, Point::new(0, -1));
+        assert_eq!(north.turn_right(), Point::from(Direction::East));
+        assert_eq!(north.turn_left(), Point::from(Direction::West));
+        assert_eq!(north.turn_around(), Point::from(Direction::South));
+        assert_eq!(Point::ORIGIN + Direction::East, Point::new(1, 0));
+        assert_eq!(Point::ORIGIN.step_n(Direction::South, 4), Point::new(0, 4));
+        assert!(Point::ORIGIN.neighbors4().contains(&Point::new(-1, 0)));
+        assert_eq!(Point::ORIGIN + Direction8::SouthWest, Point::new(-1, 1));
+        assert_eq!(Point::ORIGIN.neighbors8()[1], Point::new(1, -1));
+    }
+
+    #[test]
+    fn test_point_grid_indices() {
+        assert_eq!(Point::from_index(7, 3), Point::new(1, 2));
+        assert_eq!(Point::new(1, 2).to_index(3, 3), Some(7));
+        assert_eq!(Point::new(3, 0).to_index(3, 3), None);
+        assert_eq!(Point::new(-1, 0).to_coords(), None);
+        assert_eq!(Point::from((2usize, 1usize)).to_coords(), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_grid_point_access() {
+        let mut grid = Grid::from_vec(vec![1, 2, 3, 4], 2);
+        assert_eq!(grid[Point::new(1, 0)], 2);
+        grid[Point::new(0, 1)] = 9;
+        assert_eq!(grid.get_point(Point::new(0, 1)), Some(&9));
+        assert_eq!(grid.get_point(Point::new(-1, 0)), None);
+        assert!(grid.contains_point(Point::new(1, 1)));
+        assert!(!grid.contains_point(Point::new(2, 1)));
+    }
+
+    #[test]
+    fn test_sparse_point_access() {
+        let mut grid = SparseGrid::new();
+        let p = Point::new(-3, 2);
+        assert_eq!(grid.insert_point(p, 1), None);
+        *grid.get_point_or_insert_with(p, || 0) += 1;
+        *grid.get_point_or_insert_with(Point::ORIGIN, || 0) += 1;
+        assert_eq!(grid.get_point(p), Some(&2));
+        assert_eq!(grid.get(0, 0), Some(&1));
+        *grid.get_point_mut(p).unwrap() = 5;
+        assert!(grid.contains_point(p));
+        assert_eq!(grid.remove_point(p), Some(5));
+        assert!(!grid.contains_point(p));
+    }
+}
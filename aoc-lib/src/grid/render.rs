@@ -0,0 +1,270 @@
+use super::{Grid, Point, SparseGrid, CARDINAL};
+use colored::Colorize;
+use std::collections::HashMap;
+use std::fmt;
+
+pub use colored::Color;
+
+/// How an overlaid cell is drawn, on top of its rendered character
+#[derive(Debug, Default, Clone, Copy)]
+struct Style {
+    ch: Option<char>,
+    color: Option<Color>,
+}
+
+/// A grid a [`Renderer`] can draw
+pub trait Canvas {
+    type Cell;
+
+    /// Returns the top-left and bottom-right corners of the area to draw, or None if there is none
+    fn area(&self) -> Option<(Point, Point)>;
+
+    /// Returns the value at a point, or None if it has none
+    fn cell(&self, p: Point) -> Option<&Self::Cell>;
+}
+
+impl<T> Canvas for Grid<T> {
+    type Cell = T;
+
+    fn area(&self) -> Option<(Point, Point)> {
+        (self.height() > 0).then(|| {
+            let corner = Point::new(self.width() as i64 - 1, self.height() as i64 - 1);
+            (Point::ORIGIN, corner)
+        })
+    }
+
+    fn cell(&self, p: Point) -> Option<&T> {
+        self.get_point(p)
+    }
+}
+
+impl<T> Canvas for SparseGrid<T> {
+    type Cell = T;
+
+    fn area(&self) -> Option<(Point, Point)> {
+        let bounds = self.bounds()?;
+        Some((
+            Point::new(bounds.min_x, bounds.min_y),
+            Point::new(bounds.max_x, bounds.max_y),
+        ))
+    }
+
+    fn cell(&self, p: Point) -> Option<&T> {
+        self.get_point(p)
+    }
+}
+
+/// Renders a grid as text, one line per row, with optional overlays.
+///
+/// Built by [`Grid::renderer`] or [`SparseGrid::renderer`] and printed through
+/// `Display`. Later overlays win where they overlap, and points outside the grid
+/// are ignored.
+pub struct Renderer<'a, G, F> {
+    grid: &'a G,
+    cell: F,
+    blank: char,
+    overlays: HashMap<Point, Style>,
+}
+
+impl<'a, G: Canvas, F> Renderer<'a, G, F>
+where
+    F: Fn(&G::Cell) -> char,
+{
+    fn new(grid: &'a G, cell: F) -> Self {
+        Renderer {
+            grid,
+            cell,
+            blank: '.',
+            overlays: HashMap::new(),
+        }
+    }
+
+    /// Draws `ch` for the unoccupied cells of a sparse grid, `.` by default
+    pub fn blank(mut self, ch: char) -> Self {
+        self.blank = ch;
+        self
+    }
+
+    /// Draws `ch` in place of the cells at `points`
+    pub fn mark(mut self, points: impl IntoIterator<Item = Point>, ch: char) -> Self {
+        for p in points {
+            self.overlays.entry(p).or_default().ch = Some(ch);
+        }
+        self
+    }
+
+    /// Colours the cells at `points`, keeping their characters
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        for p in points {
+            self.overlays.entry(p).or_default().color = Some(color);
+        }
+        self
+    }
+
+    /// Draws a path in colour, each cell showing an arrow (`^v<>`) towards the next one.
+    ///
+    /// Cells followed by a non-adjacent one show `+`, and the last cell keeps its character.
+    pub fn path(mut self, points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        let points: Vec<Point> = points.into_iter().collect();
+        for (i, &p) in points.iter().enumerate() {
+            let style = self.overlays.entry(p).or_default();
+            style.color = Some(color);
+            if let Some(&next) = points.get(i + 1) {
+                let arrow = CARDINAL
+                    .iter()
+                    .find(|&&dir| p + dir == next)
+                    .map_or('+', |dir| dir.arrow());
+                style.ch = Some(arrow);
+            }
+        }
+        self
+    }
+}
+
+impl<G: Canvas, F> fmt::Display for Renderer<'_, G, F>
+where
+    F: Fn(&G::Cell) -> char,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.grid.area() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let p = Point::new(x, y);
+                let style = self.overlays.get(&p);
+                let ch = style.and_then(|s| s.ch).unwrap_or_else(|| {
+                    self.grid
+                        .cell(p)
+                        .map_or(self.blank, |value| (self.cell)(value))
+                });
+                match style.and_then(|s| s.color) {
+                    Some(color) => write!(f, "{}", ch.to_string().color(color))?,
+                    None => write!(f, "{}", ch)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns a renderer drawing each cell as the char from `f`, to add overlays to
+    pub fn renderer<F>(&self, f: F) -> Renderer<'_, Self, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        Renderer::new(self, f)
+    }
+
+    /// Renders the grid as lines of text, one char per cell from `f`
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        self.renderer(f).to_string()
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.renderer(|&c| c))
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Returns a renderer of the bounding box drawing each occupied cell as the char from `f`
+    pub fn renderer<F>(&self, f: F) -> Renderer<'_, Self, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        Renderer::new(self, f)
+    }
+
+    /// Renders the bounding box as lines of text, one char per occupied cell from `f` and `.` elsewhere
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        self.renderer(f).to_string()
+    }
+}
+
+impl fmt::Display for SparseGrid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.renderer(|&c| c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse_chars(&["#..", "...", "..#"])
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(grid().to_string(), "#..\n...\n..#\n");
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::from_vec(vec![1, 0, 0, 1], 2);
+        assert_eq!(grid.render(|&v| if v == 1 { '#' } else { ' ' }), "# \n #\n");
+    }
+
+    #[test]
+    fn test_mark() {
+        let text = grid()
+            .renderer(|&c| c)
+            .mark([Point::new(1, 1), Point::new(5, 5)], 'O')
+            .to_string();
+        assert_eq!(text, "#..\n.O.\n..#\n");
+    }
+
+    #[test]
+    fn test_sparse_render() {
+        let mut grid = SparseGrid::new();
+        grid.insert(-1, -1, 'a');
+        grid.insert(1, 0, 'b');
+        assert_eq!(grid.to_string(), "a..\n..b\n");
+        let text = grid
+            .renderer(|&c| c.to_ascii_uppercase())
+            .blank(' ')
+            .mark([Point::new(0, 0)], 'O')
+            .to_string();
+        assert_eq!(text, "A  \n OB\n");
+        assert_eq!(SparseGrid::<char>::new().render(|_| '#'), "");
+    }
+
+    #[test]
+    fn test_highlight_and_path() {
+        let red = |s: &str| s.color(Color::Red).to_string();
+        let text = grid()
+            .renderer(|&c| c)
+            .highlight([Point::new(0, 0)], Color::Red)
+            .to_string();
+        assert_eq!(text, format!("{}..\n...\n..#\n", red("#")));
+
+        let path = [
+            Point::new(0, 1),
+            Point::new(1, 1),
+            Point::new(1, 0),
+            Point::new(2, 2),
+        ];
+        let text = grid().renderer(|&c| c).path(path, Color::Red).to_string();
+        assert_eq!(
+            text,
+            format!(
+                "#{}.\n{}{}.\n..{}\n",
+                red("+"),
+                red(">"),
+                red("^"),
+                red("#")
+            )
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use super::{Direction, Grid, ALL_8, CARDINAL};
+use std::collections::HashMap;
+
+/// The inclusive bounding box of the cells of a [`SparseGrid`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    /// Returns the bounds of the single cell (x, y)
+    pub fn of(x: i64, y: i64) -> Self {
+        Bounds {
+            min_x: x,
+            min_y: y,
+            max_x: x,
+            max_y: y,
+        }
+    }
+
+    /// Grows the bounds to include (x, y)
+    pub fn extend(&mut self, x: i64, y: i64) {
+        self.min_x = self.min_x.min(x);
+        self.min_y = self.min_y.min(y);
+        self.max_x = self.max_x.max(x);
+        self.max_y = self.max_y.max(y);
+    }
+
+    /// Checks if (x, y) lies within the bounds
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    /// Checks if (x, y) lies on the edge of the bounds
+    fn on_edge(&self, x: i64, y: i64) -> bool {
+        x == self.min_x || x == self.max_x || y == self.min_y || y == self.max_y
+    }
+
+    /// Returns the number of columns covered
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x + 1) as usize
+    }
+
+    /// Returns the number of rows covered
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y + 1) as usize
+    }
+}
+
+/// An unbounded 2D grid holding values at signed coordinates.
+///
+/// Only occupied cells are stored, so it suits puzzles walking an infinite plane.
+/// Coordinates follow [`Grid`]: x grows east and y grows south.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a grid from puzzle input, keeping the cells for which `f` returns a value.
+    ///
+    /// The first character of the first line is at (0, 0).
+    pub fn parse_str<F>(input: &str, f: F) -> Self
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut grid = SparseGrid::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                if let Some(value) = f(ch) {
+                    grid.insert(x as i64, y as i64, value);
+                }
+            }
+        }
+        grid
+    }
+
+    /// Stores a value at (x, y), returning the value it replaces
+    pub fn insert(&mut self, x: i64, y: i64, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(x, y),
+            None => self.bounds = Some(Bounds::of(x, y)),
+        }
+        self.cells.insert((x, y), value)
+    }
+
+    /// Removes the value at (x, y), shrinking the bounds if needed
+    pub fn remove(&mut self, x: i64, y: i64) -> Option<T> {
+        let value = self.cells.remove(&(x, y))?;
+        if self.bounds.is_some_and(|bounds| bounds.on_edge(x, y)) {
+            self.bounds = self
+                .cells
+                .keys()
+                .fold(None, |bounds, &(x, y)| match bounds {
+                    Some(mut bounds) => {
+                        bounds.extend(x, y);
+                        Some(bounds)
+                    }
+                    None => Some(Bounds::of(x, y)),
+                });
+        }
+        Some(value)
+    }
+
+    /// Gets a reference to the value at (x, y) if occupied
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.cells.get(&(x, y))
+    }
+
+    /// Gets a mutable reference to the value at (x, y) if occupied
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.cells.get_mut(&(x, y))
+    }
+
+    /// Gets a mutable reference to the value at (x, y), inserting `f()` if unoccupied
+    pub fn get_or_insert_with<F>(&mut self, x: i64, y: i64, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        if !self.cells.contains_key(&(x, y)) {
+            self.insert(x, y, f());
+        }
+        self.cells.get_mut(&(x, y)).expect("cell was just inserted")
+    }
+
+    /// Checks if (x, y) holds a value
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.cells.contains_key(&(x, y))
+    }
+
+    /// Returns the number of occupied cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Checks if no cell is occupied
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the bounding box of the occupied cells, or None if there are none
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Returns an iterator over the occupied cells with their coordinates, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (i64, i64, &T)> {
+        self.cells.iter().map(|(&(x, y), v)| (x, y, v))
+    }
+
+    /// Returns the occupied cells among the 4 cardinal neighbors of (x, y)
+    pub fn neighbors4(&self, x: i64, y: i64) -> impl Iterator<Item = (i64, i64, &T)> {
+        CARDINAL
+            .iter()
+            .filter_map(move |dir| self.neighbor(x, y, *dir))
+    }
+
+    /// Returns the occupied cells among all 8 neighbors (cardinal + diagonal) of (x, y)
+    pub fn neighbors8(&self, x: i64, y: i64) -> impl Iterator<Item = (i64, i64, &T)> {
+        ALL_8.iter().filter_map(move |&(dx, dy)| {
+            let (nx, ny) = (x + dx as i64, y + dy as i64);
+            self.get(nx, ny).map(|v| (nx, ny, v))
+        })
+    }
+
+    /// Returns the neighbor in a specific direction if occupied
+    pub fn neighbor(&self, x: i64, y: i64, dir: Direction) -> Option<(i64, i64, &T)> {
+        let (dx, dy) = dir.offset();
+        let (nx, ny) = (x + dx as i64, y + dy as i64);
+        self.get(nx, ny).map(|v| (nx, ny, v))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Converts the bounding box to a dense grid, filling unoccupied cells with `default`.
+    ///
+    /// Cell (x, y) moves to (x - min_x, y - min_y). An empty grid gives a 0x0 grid.
+    pub fn to_grid(&self, default: T) -> Grid<T> {
+        let Some(bounds) = self.bounds else {
+            return Grid::new(0, 0, default);
+        };
+        let mut grid = Grid::new(bounds.width(), bounds.height(), default);
+        for (x, y, v) in self.iter() {
+            grid[((x - bounds.min_x) as usize, (y - bounds.min_y) as usize)] = v.clone();
+        }
+        grid
+    }
+}
+
+impl<T> FromIterator<((i64, i64), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i64, i64), T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<((i64, i64), T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = ((i64, i64), T)>>(&mut self, iter: I) {
+        for ((x, y), value) in iter {
+            self.insert(x, y, value);
+        }
+    }
+}
+
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+    fn from(grid: &Grid<T>) -> Self {
+        grid.iter()
+            .map(|(x, y, v)| ((x as i64, y as i64), v.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plus() -> SparseGrid<char> {
+        //  .#.
+        //  ###
+        //  .#.
+        SparseGrid::parse_str(".#.\n###\n.#.\n", |c| (c == '#').then_some(c))
+    }
+
+    #[test]
+    fn test_sparse_insert_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.insert(-2, 3, 1), None);
+        assert_eq!(grid.insert(4, -1, 2), None);
+        assert_eq!(grid.insert(4, -1, 3), Some(2));
+        assert_eq!(grid.len(), 2);
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            (bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y),
+            (-2, -1, 4, 3)
+        );
+        assert_eq!((bounds.width(), bounds.height()), (7, 5));
+        assert!(bounds.contains(0, 0));
+        assert!(!bounds.contains(5, 0));
+    }
+
+    #[test]
+    fn test_sparse_remove_shrinks_bounds() {
+        let mut grid: SparseGrid<()> = [((0, 0), ()), ((5, 5), ()), ((2, 1), ())]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.remove(5, 5), Some(()));
+        assert_eq!(grid.remove(5, 5), None);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min_x: 0,
+                min_y: 0,
+                max_x: 2,
+                max_y: 1
+            })
+        );
+        grid.remove(0, 0);
+        grid.remove(2, 1);
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_sparse_get_or_insert_with() {
+        let mut grid = SparseGrid::new();
+        *grid.get_or_insert_with(-1, -1, || 0) += 1;
+        *grid.get_or_insert_with(-1, -1, || 0) += 1;
+        assert_eq!(grid.get(-1, -1), Some(&2));
+        assert_eq!(grid.bounds(), Some(Bounds::of(-1, -1)));
+    }
+
+    #[test]
+    fn test_sparse_neighbors() {
+        let grid = plus();
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(0, 0).count(), 3);
+        assert_eq!(grid.neighbor(1, 1, Direction::North), Some((1, 0, &'#')));
+        assert_eq!(grid.neighbor(1, 0, Direction::North), None);
+    }
+
+    #[test]
+    fn test_sparse_to_grid() {
+        let mut grid = plus();
+        grid.insert(-1, 1, '#');
+        let dense = grid.to_grid('.');
+        assert_eq!((dense.width(), dense.height()), (4, 3));
+        assert_eq!(dense[(0, 1)], '#');
+        assert_eq!(dense[(0, 0)], '.');
+        assert_eq!(SparseGrid::from(&dense).len(), 12);
+        assert_eq!(SparseGrid::<char>::new().to_grid('.').width(), 0);
+    }
+}
@@ -0,0 +1,159 @@
+use anyhow::{Context, Result};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Environment variable pointing at a directory of inputs laid out as `<year>/day<N>.in`
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's usual input file, see [`default_path`]
+    #[default]
+    Default,
+    /// An explicit file, e.g. a puzzle example or another account's input
+    File(PathBuf),
+    /// Standard input, written as `-` on the command line
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl InputSource {
+    /// Reads the puzzle input for the given day from this source
+    pub fn load(&self, year_dir: &Path, year: u16, day: u8) -> Result<String> {
+        match self {
+            InputSource::Default => {
+                let path = default_path(year_dir, year, day);
+                let input = match fs::read_to_string(&path) {
+                    Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+                    result => result
+                        .with_context(|| format!("Failed to read file: {}", path.display()))?,
+                };
+                if input.trim().is_empty() {
+                    return Err(MissingInput(path).into());
+                }
+                Ok(input)
+            }
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .context("Failed to read input from stdin")?;
+                Ok(content)
+            }
+        }
+    }
+}
+
+/// The error loading a day's usual input reports when the file is missing or empty
+#[derive(Debug)]
+pub struct MissingInput(pub PathBuf);
+
+impl fmt::Display for MissingInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No puzzle input in {}", self.0.display())
+    }
+}
+
+impl std::error::Error for MissingInput {}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read file: {}", path.display()))
+}
+
+/// Returns the usual input file of a day.
+///
+/// This is `$AOC_INPUT_DIR/<year>/day<N>.in` when the variable is set, and
+/// `<year_dir>/input/day<N>.in` otherwise.
+pub fn default_path(year_dir: &Path, year: u16, day: u8) -> PathBuf {
+    path_in(
+        env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+        year_dir,
+        year,
+        day,
+    )
+}
+
+fn path_in(input_dir: Option<PathBuf>, year_dir: &Path, year: u16, day: u8) -> PathBuf {
+    let file = format!("day{}.in", day);
+    match input_dir {
+        Some(dir) => dir.join(year.to_string()).join(file),
+        None => year_dir.join("input").join(file),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_in_year_dir() {
+        assert_eq!(
+            path_in(None, Path::new("2015"), 2015, 7),
+            PathBuf::from("2015/input/day7.in")
+        );
+    }
+
+    #[test]
+    fn test_path_in_input_dir() {
+        assert_eq!(
+            path_in(Some(PathBuf::from("/inputs")), Path::new("2015"), 2015, 7),
+            PathBuf::from("/inputs/2015/day7.in")
+        );
+    }
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "examples/day1.in".parse(),
+            Ok(InputSource::File(PathBuf::from("examples/day1.in")))
+        );
+    }
+
+    #[test]
+    fn test_load_file() {
+        let path =
+            env::temp_dir().join(format!("aoc-lib-test-load-file-{}.in", std::process::id()));
+        fs::write(&path, "R2, L3\n").unwrap();
+        let source = InputSource::File(path.clone());
+        assert_eq!(source.load(Path::new("2016"), 2016, 1).unwrap(), "R2, L3\n");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let source = InputSource::File(PathBuf::from("no/such/day1.in"));
+        let err = source.load(Path::new("2016"), 2016, 1).unwrap_err();
+        assert!(err.to_string().contains("no/such/day1.in"));
+    }
+
+    #[test]
+    fn test_load_empty_default() {
+        let year_dir =
+            env::temp_dir().join(format!("aoc-lib-test-empty-default-{}", std::process::id()));
+        fs::create_dir_all(year_dir.join("input")).unwrap();
+        fs::write(year_dir.join("input").join("day3.in"), "\n").unwrap();
+        let err = InputSource::Default.load(&year_dir, 2015, 3).unwrap_err();
+        assert!(err.is::<MissingInput>());
+        let err = InputSource::Default.load(&year_dir, 2015, 4).unwrap_err();
+        assert!(err.to_string().contains("day4.in"));
+        assert!(err.is::<MissingInput>());
+        fs::remove_dir_all(year_dir).unwrap();
+    }
+}
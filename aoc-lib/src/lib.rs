@@ -10,14 +10,36 @@ pub mod math;
 pub use math::{gcd, lcm};
 
 pub mod parsing;
-pub use parsing::{extract_numbers, extract_ints};
+pub use parsing::{
+    extract_ints, extract_numbers, parse_lines, parse_lines_keep_empty, parse_one_per_line,
+    parse_single_line,
+};
 
 pub mod grid;
-pub use grid::Grid;
+pub use grid::{Grid, Point, SparseGrid};
 
 pub mod pathfinding;
 pub use pathfinding::{bfs, dijkstra};
 
+pub mod answer;
+pub use answer::Answer;
+
+pub mod solution;
+pub use solution::{Day, Registry, Solution};
+
+pub mod parallel;
+
+pub mod runner;
+
+pub mod report;
+
+pub mod verify;
+
+pub mod testing;
+
+pub mod input;
+pub use input::InputSource;
+
 /// Reads lines from a file and returns a vector of non-empty strings.
 ///
 /// # Arguments
@@ -40,12 +62,8 @@ pub fn read_lines<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
     let path_ref = path.as_ref();
     let content = read_to_string(path_ref)
         .with_context(|| format!("Failed to read file: {}", path_ref.display()))?;
-    
-    Ok(content
-            .lines()
-            .filter(|line| !line.trim().is_empty())
-            .map(String::from)
-        .collect())
+
+    Ok(parse_lines(&content))
 }
 
 /// Reads a file and returns all lines, including empty ones, as a vector of strings.
@@ -86,11 +104,10 @@ pub fn read_lines<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
 /// lines. The newline characters themselves are not included in the resulting strings.
 pub fn read_lines_keep_empty<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
     let path_ref = path.as_ref();
-    Ok(read_to_string(path_ref)
-        .with_context(|| format!("Failed to read file: {}", path_ref.display()))?
-        .lines()
-        .map(String::from)
-        .collect())
+    let content = read_to_string(path_ref)
+        .with_context(|| format!("Failed to read file: {}", path_ref.display()))?;
+
+    Ok(parse_lines_keep_empty(&content))
 }
 
 /// Reads a file and returns its content as a vector of characters.
@@ -151,21 +168,9 @@ where
     let path_ref = path.as_ref();
     let content = read_to_string(path_ref)
         .with_context(|| format!("Failed to read file: {}", path_ref.display()))?;
-    
-    let first_line = content
-        .lines()
-        .next()
-        .with_context(|| format!("File is empty: {}", path_ref.display()))?;
-    
-    let parsed_values: Result<Vec<T>, _> = first_line
-        .split(sep)
-        .map(|part| {
-            part.trim().parse::<T>()
-                .map_err(|e| anyhow::anyhow!("Failed to parse '{}': {:?}", part.trim(), e))
-        })
-        .collect();
-    
-    parsed_values.with_context(|| format!("Failed to parse line from file: {}", path_ref.display()))
+
+    parse_single_line(&content, sep)
+        .with_context(|| format!("Failed to parse line from file: {}", path_ref.display()))
 }
 
 /// Reads a file and parses each non-empty line into a value of type T.
@@ -199,16 +204,7 @@ where
     let path_ref = path.as_ref();
     let content = read_to_string(path_ref)
         .with_context(|| format!("Failed to read file: {}", path_ref.display()))?;
-    
-    let parsed_values: Result<Vec<T>, _> = content
-            .lines()
-            .filter(|line| !line.trim().is_empty())
-        .enumerate()
-        .map(|(line_num, line)| {
-            line.parse::<T>()
-                .map_err(|e| anyhow::anyhow!("Failed to parse line {}: '{}': {:?}", line_num + 1, line, e))
-        })
-        .collect();
-    
-    parsed_values.with_context(|| format!("Failed to parse lines from file: {}", path_ref.display()))
+
+    parse_one_per_line(&content)
+        .with_context(|| format!("Failed to parse lines from file: {}", path_ref.display()))
 }
@@ -61,6 +61,7 @@ pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
 
 /// Checks if a number is prime using trial division for small numbers
 /// and Miller-Rabin for larger ones.
+#[allow(clippy::manual_is_multiple_of)]
 pub fn is_prime(n: u64) -> bool {
     if n <= 1 {
         return false;
@@ -109,6 +110,7 @@ pub fn primes_up_to(limit: usize) -> Vec<usize> {
 }
 
 /// Returns the prime factorization of n as a vector of prime factors.
+#[allow(clippy::manual_is_multiple_of)]
 pub fn prime_factors(mut n: u64) -> Vec<u64> {
     let mut factors = Vec::new();
 
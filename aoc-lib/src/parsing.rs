@@ -1,6 +1,57 @@
+use anyhow::{Context, Result};
 use regex::Regex;
 use std::str::FromStr;
 
+/// Splits puzzle input into its non-empty lines.
+pub fn parse_lines(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Splits puzzle input into lines, keeping empty ones as section separators.
+pub fn parse_lines_keep_empty(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+/// Splits the first line of the input by `sep` and parses each part.
+pub fn parse_single_line<T>(input: &str, sep: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Debug,
+{
+    let first_line = input.lines().next().context("Input is empty")?;
+
+    first_line
+        .split(sep)
+        .map(|part| {
+            part.trim()
+                .parse::<T>()
+                .map_err(|e| anyhow::anyhow!("Failed to parse '{}': {:?}", part.trim(), e))
+        })
+        .collect()
+}
+
+/// Parses each non-empty line of the input.
+pub fn parse_one_per_line<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Debug,
+{
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(line_num, line)| {
+            line.parse::<T>().map_err(|e| {
+                anyhow::anyhow!("Failed to parse line {}: '{}': {:?}", line_num + 1, line, e)
+            })
+        })
+        .collect()
+}
+
 /// Extracts all integers (including negatives) from a string.
 pub fn extract_numbers(s: &str) -> Vec<i64> {
     let re = Regex::new(r"-?\d+").unwrap();
@@ -43,18 +94,19 @@ pub fn scan_pattern(s: &str, pattern: &str) -> Option<Vec<String>> {
 }
 
 /// Splits lines into groups separated by empty lines.
-pub fn split_groups(lines: &[String]) -> Vec<Vec<String>> {
+pub fn split_groups<S: AsRef<str>>(lines: &[S]) -> Vec<Vec<String>> {
     let mut groups = Vec::new();
     let mut current_group = Vec::new();
 
     for line in lines {
+        let line = line.as_ref();
         if line.trim().is_empty() {
             if !current_group.is_empty() {
                 groups.push(current_group);
                 current_group = Vec::new();
             }
         } else {
-            current_group.push(line.clone());
+            current_group.push(line.to_string());
         }
     }
 
@@ -66,21 +118,23 @@ pub fn split_groups(lines: &[String]) -> Vec<Vec<String>> {
 }
 
 /// Splits lines based on a custom predicate.
-pub fn split_on<F>(lines: &[String], predicate: F) -> Vec<Vec<String>>
+pub fn split_on<S, F>(lines: &[S], predicate: F) -> Vec<Vec<String>>
 where
+    S: AsRef<str>,
     F: Fn(&str) -> bool,
 {
     let mut groups = Vec::new();
     let mut current_group = Vec::new();
 
     for line in lines {
+        let line = line.as_ref();
         if predicate(line) {
             if !current_group.is_empty() {
                 groups.push(current_group);
                 current_group = Vec::new();
             }
         } else {
-            current_group.push(line.clone());
+            current_group.push(line.to_string());
         }
     }
 
@@ -105,6 +159,28 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("a\n\nb\n"), vec!["a", "b"]);
+        assert_eq!(parse_lines_keep_empty("a\n\nb\n"), vec!["a", "", "b"]);
+        assert!(parse_lines("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_single_line() {
+        let values: Vec<i32> = parse_single_line("1, 2,3\n4", ",").unwrap();
+        assert_eq!(values, vec![1, 2, 3]);
+        assert!(parse_single_line::<i32>("", ",").is_err());
+        assert!(parse_single_line::<i32>("1,x", ",").is_err());
+    }
+
+    #[test]
+    fn test_parse_one_per_line() {
+        let values: Vec<u32> = parse_one_per_line("20\n15\n\n10\n").unwrap();
+        assert_eq!(values, vec![20, 15, 10]);
+        assert!(parse_one_per_line::<u32>("1\nx").is_err());
+    }
+
     #[test]
     fn test_extract_numbers() {
         assert_eq!(extract_numbers("move x=10, y=-5"), vec![10, -5]);
@@ -149,6 +225,10 @@ mod tests {
         assert_eq!(groups.len(), 2);
         assert_eq!(groups[0], vec!["line1", "line2"]);
         assert_eq!(groups[1], vec!["line3"]);
+
+        let input = "a\nb\n\nc";
+        let groups = split_groups(&input.lines().collect::<Vec<_>>());
+        assert_eq!(groups, vec![vec!["a", "b"], vec!["c"]]);
     }
 
     #[test]
@@ -166,6 +246,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_parse_or_panic() {
         assert_eq!(parse_or_panic::<i32>("42"), 42);
         assert_eq!(parse_or_panic::<f64>("3.14"), 3.14);
@@ -269,6 +269,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::cast_abs_to_unsigned)]
     fn test_astar_finds_path() {
         // Simple heuristic: distance to goal
         let heuristic = |&n: &i32| (4 - n).abs() as usize;
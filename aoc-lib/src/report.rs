@@ -0,0 +1,185 @@
+use crate::answer::Answer;
+use crate::runner::PartRun;
+use anyhow::Result;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+/// How run results are printed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable output with colours
+    #[default]
+    Text,
+    /// A JSON array of [`Record`]s
+    Json,
+    /// A header row followed by one row per [`Record`]
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "Invalid format: {} (expected text, json or csv)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Csv => "csv",
+        };
+        f.pad(name)
+    }
+}
+
+/// The machine-readable result of one part
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `ok`, `unsolved`, `missing`, `error` or `panic`
+    pub status: &'static str,
+    pub answer: Option<Answer>,
+    /// How long the part took, or its mean time when benchmarked
+    pub duration_ns: f64,
+    /// The 95% confidence interval of the mean, when benchmarked
+    pub ci95_low_ns: Option<f64>,
+    pub ci95_high_ns: Option<f64>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// Builds the record of a run
+    pub fn of(run: &PartRun) -> Self {
+        let (answer, error) = match &run.answer {
+            Ok(answer) => (Some(answer.clone()), None),
+            Err(e) => (None, Some(format!("{:#}", e))),
+        };
+        Record {
+            year: run.year,
+            day: run.day,
+            part: run.part,
+            status: run.status(),
+            answer,
+            duration_ns: run.elapsed.as_nanos() as f64,
+            ci95_low_ns: run.stats.as_ref().and_then(|stats| stats.ci95_low_ns),
+            ci95_high_ns: run.stats.as_ref().and_then(|stats| stats.ci95_high_ns),
+            error,
+        }
+    }
+}
+
+/// Formats records as a pretty-printed JSON array
+pub fn to_json(records: &[Record]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(records)?)
+}
+
+/// Formats records as CSV with a header row, quoting fields where needed
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv =
+        String::from("year,day,part,status,answer,duration_ns,ci95_low_ns,ci95_high_ns,error\n");
+    for record in records {
+        let fields = [
+            record.year.to_string(),
+            record.day.to_string(),
+            record.part.to_string(),
+            record.status.to_string(),
+            record
+                .answer
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default(),
+            format!("{:.0}", record.duration_ns),
+            record
+                .ci95_low_ns
+                .map(|ns| format!("{:.0}", ns))
+                .unwrap_or_default(),
+            record
+                .ci95_high_ns
+                .map(|ns| format!("{:.0}", ns))
+                .unwrap_or_default(),
+            record.error.clone().unwrap_or_default(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|field| quote(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmark::Stats;
+    use anyhow::anyhow;
+    use std::time::Duration;
+
+    fn records() -> Vec<Record> {
+        let run = |part, answer| PartRun {
+            year: 2015,
+            day: 1,
+            part,
+            answer,
+            elapsed: Duration::from_micros(1500),
+            stats: None,
+        };
+        let samples = [1400, 1600].map(Duration::from_micros);
+        vec![
+            Record::of(&PartRun {
+                stats: Some(Stats::of(&samples, 1)),
+                ..run(1, Ok(Answer::from(74)))
+            }),
+            Record::of(&run(2, Err(anyhow!("bad input, \"x\"")))),
+        ]
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+        assert_eq!(Format::Csv.to_string().parse::<Format>(), Ok(Format::Csv));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records()).unwrap()).unwrap();
+        assert_eq!(json[0]["answer"], 74);
+        assert_eq!(json[0]["duration_ns"], 1_500_000.0);
+        assert_eq!(json[0]["error"], serde_json::Value::Null);
+        assert!((json[0]["ci95_low_ns"].as_f64().unwrap() - 1_304_000.0).abs() < 1.0);
+        assert_eq!(json[1]["ci95_high_ns"], serde_json::Value::Null);
+        assert_eq!(json[1]["status"], "error");
+        assert_eq!(json[1]["error"], "bad input, \"x\"");
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&records()),
+            "year,day,part,status,answer,duration_ns,ci95_low_ns,ci95_high_ns,error\n\
+             2015,1,1,ok,74,1500000,1304000,1696000,\n\
+             2015,1,2,error,,1500000,,,\"bad input, \"\"x\"\"\"\n"
+        );
+    }
+}
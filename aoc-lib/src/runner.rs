@@ -0,0 +1,444 @@
+use crate::answer::Answer;
+use crate::benchmark::{format_duration, Stats};
+use crate::input::{InputSource, MissingInput};
+use crate::parallel::{default_jobs, parallel_map};
+use crate::report::{to_csv, to_json, Format, Record};
+use crate::solution::{Day, Registry, Solver};
+use anyhow::{anyhow, bail, Context, Result};
+use colored::*;
+use std::any::Any;
+use std::env;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// The outcome of running one part of a day in-process
+#[derive(Debug)]
+pub struct PartRun {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+    // set when the part was benchmarked rather than run once
+    pub stats: Option<Stats>,
+}
+
+impl PartRun {
+    /// Returns `ok`, `unsolved`, `missing`, `error` or `panic` depending on how the solver finished
+    pub fn status(&self) -> &'static str {
+        match &self.answer {
+            Ok(_) => "ok",
+            Err(e) if e.is::<Unsolved>() => "unsolved",
+            Err(e) if e.is::<MissingInput>() => "missing",
+            Err(e) if e.is::<Panicked>() => "panic",
+            Err(_) => "error",
+        }
+    }
+
+    /// Whether the part failed, as opposed to answering or being skipped
+    pub fn failed(&self) -> bool {
+        self.answer.as_ref().is_err_and(|e| !is_skipped(e))
+    }
+}
+
+/// Whether an error means the part was skipped, being unsolved or without input, rather than failed
+pub fn is_skipped(error: &anyhow::Error) -> bool {
+    error.is::<Unsolved>() || error.is::<MissingInput>()
+}
+
+/// The error a solver returns while its part isn't solved yet, so runs skip it instead of failing
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not solved yet")
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
+/// The error a part reports when its solver panicked
+#[derive(Debug)]
+pub struct Panicked(pub String);
+
+impl fmt::Display for Panicked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "panicked: {}", self.0)
+    }
+}
+
+impl std::error::Error for Panicked {}
+
+impl Panicked {
+    fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "unknown cause".to_string(),
+            },
+        };
+        Panicked(message)
+    }
+}
+
+/// Entry point shared by every year's `main` binary, run from the year directory.
+///
+/// With no arguments every registered day is run. `list` prints the registered
+/// days, and `dayN` (or just `N`) runs a single day. `--input <file>` reads a
+/// single day's input from `file` instead, or from stdin when `file` is `-`.
+/// `--part <1|2>` runs only that part, `--jobs <n>` sets how many parts run
+/// at once when running every day, and `--format <text|json|csv>` picks how
+/// results are printed.
+pub fn main(registry: &Registry) -> Result<()> {
+    let mut args = env::args().skip(1);
+    let mut command = None;
+    let mut source = None;
+    let mut part = None;
+    let mut jobs = default_jobs();
+    let mut format = Format::Text;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args.next().context("--part needs 1 or 2")?;
+                part = Some(parse_part(&value)?);
+            }
+            "--jobs" => {
+                let value = args.next().context("--jobs needs a number")?;
+                jobs = value
+                    .parse()
+                    .with_context(|| format!("Invalid number of jobs: {}", value))?;
+            }
+            "--format" => {
+                let value = args.next().context("--format needs text, json or csv")?;
+                format = value.parse().map_err(|e: String| anyhow!(e))?;
+            }
+            "--input" => {
+                let value = args
+                    .next()
+                    .context("--input needs a file, or - for stdin")?;
+                source = Some(value.parse::<InputSource>()?);
+            }
+            _ if command.is_none() => command = Some(arg),
+            _ => bail!("Unexpected argument: {}", arg),
+        }
+    }
+
+    let year_dir = Path::new(".");
+    match command.as_deref() {
+        None | Some("list") if source.is_some() => bail!("--input needs a single day"),
+        None => run_all(registry, year_dir, part, jobs, format),
+        Some("list") => {
+            list(registry);
+            Ok(())
+        }
+        Some(arg) => {
+            let day = parse_day(arg)?;
+            let Some(day) = registry.get(day) else {
+                bail!("Day {} of {} is not implemented", day, registry.year());
+            };
+            if let Some(part) = part {
+                if day.solver(part).is_none() {
+                    bail!(
+                        "Day {} part {} of {} is not implemented",
+                        day.day,
+                        part,
+                        day.year
+                    );
+                }
+            }
+            let input = source
+                .unwrap_or_default()
+                .load(year_dir, day.year, day.day)?;
+            run_day(day, &input, part, format)
+        }
+    }
+}
+
+/// Parses a day argument such as `day7` or `7`
+pub fn parse_day(arg: &str) -> Result<u8> {
+    arg.strip_prefix("day")
+        .unwrap_or(arg)
+        .parse()
+        .with_context(|| format!("Invalid day: {}", arg))
+}
+
+/// Parses a part argument, which must be `1` or `2`
+pub fn parse_part(arg: &str) -> Result<u8> {
+    match arg.parse() {
+        Ok(part @ (1 | 2)) => Ok(part),
+        _ => bail!("Invalid part: {} (expected 1 or 2)", arg),
+    }
+}
+
+/// Calls a solver on `input`, reporting a panic as a [`Panicked`] error
+pub fn call_solver(solver: Solver, input: &str) -> Result<Answer> {
+    panic::catch_unwind(AssertUnwindSafe(|| solver(input)))
+        .unwrap_or_else(|payload| Err(Panicked::from_payload(payload).into()))
+}
+
+/// Runs a single part on `input` and times it, or returns `None` if the part isn't implemented.
+///
+/// A panicking solver is caught and reported as a [`Panicked`] error.
+pub fn run_part(day: &Day, part: u8, input: &str) -> Option<PartRun> {
+    let solver = day.solver(part)?;
+    let start = Instant::now();
+    let answer = call_solver(solver, input);
+    Some(PartRun {
+        year: day.year,
+        day: day.day,
+        part,
+        answer,
+        elapsed: start.elapsed(),
+        stats: None,
+    })
+}
+
+/// Runs every implemented part of a day on `input`, or only `part`, and prints the answers
+pub fn run_day(day: &Day, input: &str, part: Option<u8>, format: Format) -> Result<()> {
+    let runs: Vec<PartRun> = day
+        .parts()
+        .filter(|&p| part.is_none_or(|part| p == part))
+        .map(|part| run_part(day, part, input).expect("parts() only yields implemented parts"))
+        .collect();
+    if format == Format::Text {
+        println!("Day {}:", day.day);
+        for run in &runs {
+            match &run.answer {
+                Ok(answer) => println!("Part {}: {}", run.part, inline(answer)),
+                Err(e) => println!("Part {}: {:#}", run.part, e),
+            }
+        }
+    } else {
+        print_runs(&runs, format)?;
+    }
+    if runs.iter().any(PartRun::failed) {
+        bail!("Day {} of {} failed", day.day, day.year);
+    }
+    Ok(())
+}
+
+/// Runs a single part on its day's usual input, or returns `None` if the part isn't implemented.
+///
+/// If the input can't be read, the part reports that as its error.
+pub fn run_default(day: &Day, part: u8, year_dir: &Path) -> Option<PartRun> {
+    day.solver(part)?;
+    match InputSource::Default.load(year_dir, day.year, day.day) {
+        Ok(input) => run_part(day, part, &input),
+        Err(e) => Some(PartRun {
+            year: day.year,
+            day: day.day,
+            part,
+            answer: Err(e),
+            elapsed: Duration::ZERO,
+            stats: None,
+        }),
+    }
+}
+
+/// Runs the given parts on their usual inputs, `jobs` at a time, returning the runs in the same order
+pub fn run_parts(
+    parts: &[(&Day, u8)],
+    year_dir: impl Fn(u16) -> PathBuf + Sync,
+    jobs: usize,
+) -> Vec<PartRun> {
+    parallel_map(parts, jobs, |&(day, part)| {
+        run_default(day, part, &year_dir(day.year)).expect("only implemented parts are run")
+    })
+}
+
+/// Formats an answer to follow a label, moving multi-line answers onto their own lines
+pub fn inline(answer: &Answer) -> String {
+    if answer.is_multiline() {
+        format!("\n{}", answer)
+    } else {
+        answer.to_string()
+    }
+}
+
+/// Shortens an answer to fit a table cell
+pub fn cell(answer: &Answer) -> String {
+    match answer {
+        Answer::Art(art) => format!("<{} lines>", art.lines().count()),
+        answer => answer.to_string(),
+    }
+}
+
+/// Runs every registered day on its usual input, `jobs` parts at a time, and prints a summary table.
+///
+/// Only `part` of each day is run when given. Errors and panics are isolated to
+/// their part, so every other day still runs.
+pub fn run_all(
+    registry: &Registry,
+    year_dir: &Path,
+    part: Option<u8>,
+    jobs: usize,
+    format: Format,
+) -> Result<()> {
+    let parts: Vec<(&Day, u8)> = registry
+        .parts()
+        .filter(|&(_, p)| part.is_none_or(|part| p == part))
+        .collect();
+    let runs = run_parts(&parts, |_| year_dir.to_path_buf(), jobs);
+    print_runs(&runs, format)?;
+
+    let failed = runs.iter().filter(|run| run.failed()).count();
+    if failed > 0 {
+        bail!("{} of {} parts failed", failed, runs.len());
+    }
+    Ok(())
+}
+
+/// Prints runs as a summary table, or as records in a machine-readable format
+pub fn print_runs(runs: &[PartRun], format: Format) -> Result<()> {
+    let records: Vec<Record> = runs.iter().map(Record::of).collect();
+    match format {
+        Format::Text => print_summary(runs),
+        Format::Json => println!("{}", to_json(&records)?),
+        Format::Csv => print!("{}", to_csv(&records)),
+    }
+    Ok(())
+}
+
+/// Prints a table of runs, followed by any multi-line answers and errors in full
+pub fn print_summary(runs: &[PartRun]) {
+    println!(
+        "{}",
+        format!(
+            "{:<4}  {:>3}  {:>4}  {:<20}  {:>9}  {:<6}",
+            "Year", "Day", "Part", "Answer", "Time", "Status"
+        )
+        .bold()
+    );
+    for run in runs {
+        let answer = match &run.answer {
+            Ok(answer) => cell(answer),
+            Err(_) => String::new(),
+        };
+        let status = match run.status() {
+            "ok" => "ok".bright_green(),
+            status @ ("unsolved" | "missing") => status.yellow(),
+            status => status.red().bold(),
+        };
+        println!(
+            "{:<4}  {:>3}  {:>4}  {}  {}  {}",
+            run.year,
+            run.day,
+            run.part,
+            format!("{:<20}", answer).bright_yellow(),
+            format!("{:>9}", format_duration(run.elapsed.as_nanos() as f64)).bright_blue(),
+            status
+        );
+    }
+
+    for run in runs {
+        let label = format!("{} day {} part {}", run.year, run.day, run.part);
+        match &run.answer {
+            Ok(answer) if answer.is_multiline() => println!("\n{}:\n{}", label, answer),
+            Ok(_) => {}
+            Err(e) => println!("\n{}", format!("{}: {:#}", label, e).red()),
+        }
+    }
+}
+
+/// Prints the registered days and which parts they implement
+pub fn list(registry: &Registry) {
+    for day in registry.days() {
+        let parts: Vec<String> = day.parts().map(|p| p.to_string()).collect();
+        println!(
+            "{} day {:>2}: part {}",
+            registry.year(),
+            day.day,
+            parts.join(", ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("day7").unwrap(), 7);
+        assert_eq!(parse_day("12").unwrap(), 12);
+        assert!(parse_day("dayx").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse_part("2").unwrap(), 2);
+        assert!(parse_part("3").is_err());
+        assert!(parse_part("x").is_err());
+    }
+
+    #[test]
+    fn test_run_part() {
+        let day = Day {
+            year: 2015,
+            day: 1,
+            part1: |input| Ok(input.trim().to_uppercase().into()),
+            part2: None,
+        };
+        let input = "abc\n";
+        let run = run_part(&day, 1, input).unwrap();
+        assert_eq!((run.year, run.day, run.part), (2015, 1, 1));
+        assert_eq!(run.answer.unwrap(), Answer::Text("ABC".to_string()));
+        assert!(run_part(&day, 2, input).is_none());
+    }
+
+    #[test]
+    fn test_inline() {
+        assert_eq!(inline(&Answer::from(74)), "74");
+        assert_eq!(inline(&Answer::from("#.\n.#\n")), "\n#.\n.#");
+    }
+
+    #[test]
+    fn test_run_part_catches_panics() {
+        let day = Day {
+            year: 2019,
+            day: 2,
+            part1: |_| Ok(1.into()),
+            part2: Some(|_| todo!()),
+        };
+        let run = run_part(&day, 2, "").unwrap();
+        assert_eq!(run.status(), "panic");
+        assert!(run
+            .answer
+            .unwrap_err()
+            .to_string()
+            .contains("not yet implemented"));
+        assert_eq!(run_part(&day, 1, "").unwrap().status(), "ok");
+    }
+
+    #[test]
+    fn test_run_part_unsolved() {
+        let day = Day {
+            year: 2017,
+            day: 3,
+            part1: |_| Err(Unsolved.into()),
+            part2: Some(|_| bail!("bad input")),
+        };
+        let run = run_part(&day, 1, "").unwrap();
+        assert_eq!(run.status(), "unsolved");
+        assert!(!run.failed());
+        assert!(run_part(&day, 2, "").unwrap().failed());
+    }
+
+    #[test]
+    fn test_run_default_missing_input() {
+        let day = Day {
+            year: 2015,
+            day: 1,
+            part1: |_| Ok(1.into()),
+            part2: Some(|_| Ok(2.into())),
+        };
+        let run = run_default(&day, 2, Path::new("no/such/year")).unwrap();
+        assert_eq!(run.status(), "missing");
+        assert!(!run.failed());
+        assert!(run_default(&day, 3, Path::new("no/such/year")).is_none());
+    }
+}
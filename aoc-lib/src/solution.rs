@@ -0,0 +1,242 @@
+use crate::answer::Answer;
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+
+/// A solved puzzle for a single day of a single year.
+///
+/// Implement this directly or, more commonly, with the [`solution!`](crate::solution)
+/// macro from a day's `mod.rs`.
+pub trait Solution {
+    /// The event year, e.g. `2015`
+    const YEAR: u16;
+    /// The puzzle day, `1..=25`
+    const DAY: u8;
+    /// Whether part 2 has been solved; the runner skips it otherwise
+    const PART2: bool = true;
+
+    /// Solves part 1 for the given puzzle input
+    fn part1(input: &str) -> Result<impl Into<Answer>>;
+
+    /// Solves part 2 for the given puzzle input
+    fn part2(_input: &str) -> Result<impl Into<Answer>> {
+        Err::<Answer, _>(anyhow!(
+            "Day {} part 2 of {} is not implemented",
+            Self::DAY,
+            Self::YEAR
+        ))
+    }
+}
+
+/// A type-erased solver for one part of a day
+pub type Solver = fn(&str) -> Result<Answer>;
+
+/// A registered day, with its solvers erased to plain function pointers
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub part1: Solver,
+    pub part2: Option<Solver>,
+}
+
+impl Day {
+    /// Builds the registry entry for a solution
+    pub fn of<S: Solution>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            part1: |input| S::part1(input).map(Into::into),
+            part2: if S::PART2 {
+                Some(|input| S::part2(input).map(Into::into))
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Returns the solver for `part` (1 or 2), if it is implemented
+    pub fn solver(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+
+    /// Returns the implemented part numbers in order
+    pub fn parts(&self) -> impl Iterator<Item = u8> + '_ {
+        (1..=2).filter(|&part| self.solver(part).is_some())
+    }
+}
+
+/// All registered days of one year, ordered by day
+#[derive(Debug, Clone)]
+pub struct Registry {
+    year: u16,
+    days: BTreeMap<u8, Day>,
+}
+
+impl Registry {
+    /// Creates an empty registry for `year`
+    pub fn new(year: u16) -> Self {
+        Registry {
+            year,
+            days: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a solution, panicking if its year doesn't match or its day is already taken
+    pub fn register<S: Solution>(mut self) -> Self {
+        let day = Day::of::<S>();
+        assert_eq!(
+            day.year, self.year,
+            "Day {} belongs to {}, not {}",
+            day.day, day.year, self.year
+        );
+        assert!(
+            self.days.insert(day.day, day).is_none(),
+            "Day {} of {} is registered twice",
+            day.day,
+            self.year
+        );
+        self
+    }
+
+    /// Returns the year this registry covers
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Looks up a registered day
+    pub fn get(&self, day: u8) -> Option<&Day> {
+        self.days.get(&day)
+    }
+
+    /// Returns an iterator over all registered days in order
+    pub fn days(&self) -> impl Iterator<Item = &Day> {
+        self.days.values()
+    }
+
+    /// Returns every implemented part of every registered day, in order
+    pub fn parts(&self) -> impl Iterator<Item = (&Day, u8)> {
+        self.days()
+            .flat_map(|day| day.parts().map(move |part| (day, part)))
+    }
+}
+
+/// Implements [`Solution`] for a day from its part solvers.
+///
+/// ```ignore
+/// pub mod part1;
+/// pub mod part2;
+///
+/// aoc_lib::solution!(Day1, 2015, 1, part1::solve, part2::solve);
+/// ```
+///
+/// Leave out the second solver while only part 1 is done.
+#[macro_export]
+macro_rules! solution {
+    ($name:ident, $year:expr, $day:expr, $part1:path) => {
+        pub struct $name;
+
+        impl $crate::Solution for $name {
+            const YEAR: u16 = $year;
+            const DAY: u8 = $day;
+            const PART2: bool = false;
+
+            fn part1(input: &str) -> ::anyhow::Result<impl ::std::convert::Into<$crate::Answer>> {
+                $part1(input)
+            }
+        }
+    };
+    ($name:ident, $year:expr, $day:expr, $part1:path, $part2:path) => {
+        pub struct $name;
+
+        impl $crate::Solution for $name {
+            const YEAR: u16 = $year;
+            const DAY: u8 = $day;
+
+            fn part1(input: &str) -> ::anyhow::Result<impl ::std::convert::Into<$crate::Answer>> {
+                $part1(input)
+            }
+
+            fn part2(input: &str) -> ::anyhow::Result<impl ::std::convert::Into<$crate::Answer>> {
+                $part2(input)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Both;
+
+    impl Solution for Both {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        fn part1(_input: &str) -> Result<impl Into<Answer>> {
+            Ok(74)
+        }
+
+        fn part2(input: &str) -> Result<impl Into<Answer>> {
+            Ok(input.len())
+        }
+    }
+
+    struct FirstOnly;
+
+    impl Solution for FirstOnly {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 2;
+        const PART2: bool = false;
+
+        fn part1(_input: &str) -> Result<impl Into<Answer>> {
+            Ok(1u64)
+        }
+    }
+
+    #[test]
+    fn test_day_of() {
+        let day = Day::of::<Both>();
+        assert_eq!((day.year, day.day), (2015, 1));
+        assert_eq!((day.part1)("(())").unwrap(), Answer::Unsigned(74));
+        assert_eq!(day.solver(2).unwrap()("(())").unwrap(), Answer::Unsigned(4));
+        assert!(day.solver(3).is_none());
+    }
+
+    #[test]
+    fn test_day_without_part2() {
+        let day = Day::of::<FirstOnly>();
+        assert!(day.part2.is_none());
+        assert_eq!(day.parts().collect::<Vec<_>>(), vec![1]);
+        assert!(FirstOnly::part2("2x3x4").is_err());
+    }
+
+    #[test]
+    fn test_registry_orders_days() {
+        let registry = Registry::new(2015)
+            .register::<FirstOnly>()
+            .register::<Both>();
+        let days: Vec<_> = registry.days().map(|d| d.day).collect();
+        assert_eq!(days, vec![1, 2]);
+        let parts: Vec<_> = registry.parts().map(|(d, p)| (d.day, p)).collect();
+        assert_eq!(parts, vec![(1, 1), (1, 2), (2, 1)]);
+        assert!(registry.get(2).is_some());
+        assert!(registry.get(3).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "registered twice")]
+    fn test_registry_rejects_duplicates() {
+        let _ = Registry::new(2015).register::<Both>().register::<Both>();
+    }
+
+    #[test]
+    #[should_panic(expected = "belongs to 2015")]
+    fn test_registry_rejects_wrong_year() {
+        let _ = Registry::new(2016).register::<Both>();
+    }
+}
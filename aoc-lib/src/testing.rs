@@ -0,0 +1,195 @@
+use crate::answer::Answer;
+use crate::solution::{Day, Registry};
+use crate::verify::DayAnswers;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// Runs one part of a day on an example, failing unless it gives `expected`
+pub fn check_example(day: &Day, part: u8, input: &str, expected: Answer) -> Result<()> {
+    let solver = day
+        .solver(part)
+        .with_context(|| format!("Day {} has no part {}", day.day, part))?;
+    let answer = solver(input).with_context(|| format!("Day {} part {}", day.day, part))?;
+    if answer != expected {
+        bail!(
+            "Day {} part {}: expected {}, got {}",
+            day.day,
+            part,
+            expected,
+            answer
+        );
+    }
+    Ok(())
+}
+
+/// Checks every answered example fixture in `dir` against the registered days.
+///
+/// Fixtures are `dayN-M.in` inputs with their answers in `dayN-M.json`, as written
+/// by `aoc examples`. Fixtures of unregistered days and parts, and of the days in
+/// `skip`, are skipped. Returns the number of parts checked, and fails with every
+/// mismatch if any.
+pub fn check_fixtures(registry: &Registry, dir: &Path, skip: &[u8]) -> Result<usize> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+    let mut fixtures = Vec::new();
+    for entry in
+        fs::read_dir(dir).with_context(|| format!("Failed to read directory: {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "in") {
+            fixtures.push(path);
+        }
+    }
+    fixtures.sort();
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for input_path in fixtures {
+        let Some(day) = fixture_day(&input_path)
+            .filter(|day| !skip.contains(day))
+            .and_then(|day| registry.get(day))
+        else {
+            continue;
+        };
+        let input = fs::read_to_string(&input_path)
+            .with_context(|| format!("Failed to read file: {}", input_path.display()))?;
+        let answers_path = input_path.with_extension("json");
+        let answers: DayAnswers = serde_json::from_str(
+            &fs::read_to_string(&answers_path)
+                .with_context(|| format!("Failed to read file: {}", answers_path.display()))?,
+        )
+        .with_context(|| format!("Failed to parse answers in {}", answers_path.display()))?;
+
+        for (part, expected) in [(1, answers.part1), (2, answers.part2)] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            if day.solver(part).is_none() {
+                continue;
+            }
+            checked += 1;
+            if let Err(e) = check_example(day, part, &input, expected) {
+                failures.push(format!("{}: {:#}", input_path.display(), e));
+            }
+        }
+    }
+    if !failures.is_empty() {
+        bail!(
+            "{} of {} examples failed:\n{}",
+            failures.len(),
+            checked,
+            failures.join("\n")
+        );
+    }
+    Ok(checked)
+}
+
+/// Parses the day out of a fixture name like `day7-1.in`
+fn fixture_day(path: &Path) -> Option<u8> {
+    let stem = path.file_stem()?.to_str()?;
+    let (day, _) = stem.strip_prefix("day")?.split_once('-')?;
+    day.parse().ok()
+}
+
+/// Generates a test running one part of a day, from the crate's `registry()`, on an example.
+///
+/// ```ignore
+/// aoc_lib::aoc_test!(day1_example, day = 1, part = 2, input = "()())", expected = 5);
+/// ```
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! aoc_test {
+    ($name:ident, day = $day:expr, part = $part:expr, input = $input:expr, expected = $expected:expr $(,)?) => {
+        #[test]
+        fn $name() {
+            let registry = crate::registry();
+            let day = registry.get($day).expect("day is registered");
+            $crate::testing::check_example(day, $part, $input, $crate::Answer::from($expected))
+                .unwrap();
+        }
+    };
+}
+
+/// Generates a test checking the crate's `registry()` against its `examples` fixtures.
+///
+/// Days whose examples the solvers can't be checked on, e.g. because the puzzle
+/// asks something else of the real input, are left out with `skip`.
+///
+/// ```ignore
+/// aoc_lib::aoc_examples!(skip = [10]);
+/// ```
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! aoc_examples {
+    () => {
+        $crate::aoc_examples!(skip = []);
+    };
+    (skip = [$($day:expr),* $(,)?]) => {
+        #[test]
+        fn examples() {
+            let dir = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+            $crate::testing::check_fixtures(&crate::registry(), &dir, &[$($day),*]).unwrap();
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+    use std::env;
+
+    struct Floors;
+
+    impl Solution for Floors {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+        const PART2: bool = false;
+
+        fn part1(input: &str) -> Result<impl Into<Answer>> {
+            Ok(input.matches('(').count() as i64 - input.matches(')').count() as i64)
+        }
+    }
+
+    fn registry() -> Registry {
+        Registry::new(2015).register::<Floors>()
+    }
+
+    #[test]
+    fn test_check_example() {
+        let registry = registry();
+        let day = registry.get(1).unwrap();
+        assert!(check_example(day, 1, "(()", Answer::from(1)).is_ok());
+        let error = check_example(day, 1, "(()", Answer::from(2)).unwrap_err();
+        assert_eq!(error.to_string(), "Day 1 part 1: expected 2, got 1");
+        assert!(check_example(day, 2, "(()", Answer::from(1)).is_err());
+    }
+
+    #[test]
+    fn test_check_fixtures() {
+        let dir = env::temp_dir().join(format!("aoc-lib-test-fixtures-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day1-1.in"), "(((\n").unwrap();
+        fs::write(dir.join("day1-1.json"), r#"{"part1": 3, "part2": 9}"#).unwrap();
+        fs::write(dir.join("day2-1.in"), "unregistered\n").unwrap();
+        assert_eq!(check_fixtures(&registry(), &dir, &[]).unwrap(), 1);
+
+        fs::write(dir.join("day1-2.in"), "())\n").unwrap();
+        fs::write(dir.join("day1-2.json"), r#"{"part1": 1}"#).unwrap();
+        let error = check_fixtures(&registry(), &dir, &[]).unwrap_err();
+        assert!(error.to_string().starts_with("1 of 2 examples failed"));
+        assert_eq!(check_fixtures(&registry(), &dir, &[1]).unwrap(), 0);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_fixtures_missing_dir() {
+        assert_eq!(
+            check_fixtures(&registry(), Path::new("no/such/dir"), &[]).unwrap(),
+            0
+        );
+    }
+}
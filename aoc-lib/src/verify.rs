@@ -0,0 +1,171 @@
+use crate::answer::Answer;
+use crate::runner::is_skipped;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Name of the file in each year directory holding that year's known answers
+pub const ANSWERS_FILE: &str = "answers.json";
+
+/// The known answers of one day
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+/// The known answers of one year, keyed by day
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+impl Answers {
+    /// Reads an answers file, treating a missing file as having no answers yet
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse answers in {}", path.display()))
+    }
+
+    /// Writes the answers back, ordered by day
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n")
+            .with_context(|| format!("Failed to write file: {}", path.display()))
+    }
+
+    /// Returns the known answer of a part, if any
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        let answers = self.days.get(&day)?;
+        match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Records the answer of a part, replacing any previous one
+    pub fn set(&mut self, day: u8, part: u8, answer: Answer) {
+        let answers = self.days.entry(day).or_default();
+        match part {
+            1 => answers.part1 = Some(answer),
+            2 => answers.part2 = Some(answer),
+            _ => {}
+        }
+    }
+}
+
+/// How a part's answer compares to the known one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The answer matches the known answer
+    Pass,
+    /// The solver returned an error
+    Fail,
+    /// The answer differs from the known answer
+    Regression,
+    /// There is no known answer to compare against
+    Unrecorded,
+    /// The part is not solved yet or has no input, so there is nothing to check
+    Skipped,
+}
+
+impl Status {
+    /// Compares a solver's outcome against the known answer
+    pub fn of(answer: &Result<Answer>, expected: Option<&Answer>) -> Self {
+        match (answer, expected) {
+            (Err(e), _) if is_skipped(e) => Status::Skipped,
+            (Err(_), _) => Status::Fail,
+            (Ok(_), None) => Status::Unrecorded,
+            (Ok(answer), Some(expected)) if answer == expected => Status::Pass,
+            (Ok(_), Some(_)) => Status::Regression,
+        }
+    }
+
+    /// Whether this status should fail a verification run
+    pub fn is_failure(self) -> bool {
+        matches!(self, Status::Fail | Status::Regression)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Regression => "regression",
+            Status::Unrecorded => "unrecorded",
+            Status::Skipped => "skipped",
+        };
+        f.pad(label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Unsolved;
+    use anyhow::anyhow;
+    use std::env;
+
+    #[test]
+    fn test_status() {
+        let known = Answer::from(74);
+        assert_eq!(
+            Status::of(&Ok(Answer::from(74u64)), Some(&known)),
+            Status::Pass
+        );
+        assert_eq!(
+            Status::of(&Ok(Answer::from(75)), Some(&known)),
+            Status::Regression
+        );
+        assert_eq!(
+            Status::of(&Err(anyhow!("boom")), Some(&known)),
+            Status::Fail
+        );
+        assert_eq!(Status::of(&Ok(Answer::from(1)), None), Status::Unrecorded);
+        assert!(Status::Regression.is_failure());
+        assert!(!Status::Unrecorded.is_failure());
+        assert_eq!(
+            Status::of(&Err(Unsolved.into()), Some(&known)),
+            Status::Skipped
+        );
+        assert!(!Status::Skipped.is_failure());
+    }
+
+    #[test]
+    fn test_answers_round_trip() {
+        let path =
+            env::temp_dir().join(format!("aoc-lib-test-answers-{}.json", std::process::id()));
+        let mut answers = Answers::default();
+        answers.set(11, 1, Answer::from("hepxxyzz"));
+        answers.set(1, 2, Answer::from(1795));
+        answers.set(1, 1, Answer::from(74));
+        answers.save(&path).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.find("\"1\"").unwrap() < contents.find("\"11\"").unwrap());
+        let loaded = Answers::load(&path).unwrap();
+        assert_eq!(loaded, answers);
+        assert_eq!(loaded.get(1, 2), Some(&Answer::Unsigned(1795)));
+        assert_eq!(loaded.get(11, 2), None);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_answers_missing_file() {
+        let answers = Answers::load(Path::new("no/such/answers.json")).unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+}
@@ -1,122 +1,516 @@
-use aoc_lib::benchmark::{benchmark, print_benchmark, store_benchmark, SAMPLE_SIZE};
+use anyhow::Result;
+use aoc::{registry, workspace_dir, year_dir};
+use aoc_lib::benchmark::{
+    benchmark, format_duration, print_benchmark, print_comparison, store_benchmark, BenchConfig,
+    Benchmarks, Comparison, MAX_BENCH_TIME, SAMPLE_SIZE,
+};
+use aoc_lib::report::Format;
+use aoc_lib::runner::{call_solver, cell, inline, is_skipped, parse_day, print_runs, PartRun};
+use aoc_lib::solution::Solver;
+use aoc_lib::{Answer, Day, InputSource, Registry};
 use clap::Parser;
 use colored::*;
-use std::process::Command;
-use std::time::Instant;
+use std::env;
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 #[derive(Parser)]
 #[command(name = "cargo-time")]
 struct Cli {
-    /// The subcommand name (ignored)
+    /// The year to benchmark, or `time` when run as `cargo time` from a year directory
     #[arg(hide = true)]
-    _subcommand: String,
+    year: String,
 
-    /// The day to benchmark (e.g., day2 or just 2)
-    day_arg: String,
+    /// The day to benchmark (e.g., day2 or just 2); every registered day when left out
+    day_arg: Option<String>,
 
     /// Run both parts
-    #[arg(long)]
+    #[arg(long, conflicts_with = "part")]
     all: bool,
 
+    /// Run only this part [default: 1]
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
     /// Store the benchmark results
     #[arg(long)]
     store: bool,
+
+    /// Read the input from this file instead, or from stdin when `-`
+    #[arg(long, value_name = "FILE")]
+    input: Option<InputSource>,
+
+    /// Print the results as text, json or csv
+    #[arg(long, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Take at most this many samples
+    #[arg(long, default_value_t = SAMPLE_SIZE)]
+    samples: usize,
+
+    /// Stop sampling after this many seconds, once a few samples are taken
+    #[arg(long, value_name = "SECONDS", default_value_t = MAX_BENCH_TIME.as_secs_f64())]
+    max_time: f64,
+
+    /// Compare against the stored benchmarks (text format only)
+    #[arg(long)]
+    compare: bool,
+
+    /// Highlight changes from the baseline beyond this percentage
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Fail if any part takes longer than this many milliseconds
+    #[arg(long, value_name = "MS")]
+    budget: Option<f64>,
 }
 
-fn parse_day(day_arg: &str) -> Result<u32, String> {
-    if day_arg.starts_with("day") {
-        day_arg[3..].parse().map_err(|_| format!("Invalid day format: {}", day_arg))
+/// How each part is benchmarked and reported
+struct Settings {
+    config: BenchConfig,
+    store: bool,
+    text: bool,
+    baseline: Option<Benchmarks>,
+    threshold: f64,
+}
+
+fn parse_year(year_arg: &str) -> Result<u16, String> {
+    if year_arg == "time" {
+        let cwd =
+            env::current_dir().map_err(|e| format!("Failed to read current directory: {}", e))?;
+        let name = cwd.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        name.parse().map_err(|_| {
+            format!(
+                "Run `cargo time` from a year directory, not {}",
+                cwd.display()
+            )
+        })
     } else {
-        day_arg.parse().map_err(|_| format!("Invalid day number: {}", day_arg))
+        year_arg
+            .parse()
+            .map_err(|_| format!("Invalid year: {}", year_arg))
     }
 }
 
-fn extract_part_result(output: &str, part: u8) -> String {
-    let prefix = format!("Part {}: ", part);
-    output
-        .lines()
-        .find(|line| line.contains(&prefix))
-        .and_then(|line| line.split(&prefix).nth(1))
-        .unwrap_or("Error: Could not parse output")
-        .to_string()
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message.red());
+    std::process::exit(1);
 }
 
-fn run_solution(year: &str, day: u32, part: u8) -> String {
-    let year_dir = format!("{}", year);
-    let day_arg = format!("day{}", day);
-    
-    let cmd = Command::new("cargo")
-        .args(["run", "--release", "--bin", "main", &day_arg])
-        .current_dir(&year_dir)
-        .output();
-
-    match cmd {
-        Ok(output) => {
-            if !output.status.success() {
-                eprintln!(
-                    "Error running day {} part {} in year {}:\n{}",
-                    day,
-                    part,
-                    year,
-                    String::from_utf8_lossy(&output.stderr)
-                );
-                std::process::exit(1);
+fn run_solution(solver: Solver, input: &str) -> Result<Answer> {
+    call_solver(solver, input)
+}
+
+/// Benchmarks one part, or returns the run of its error without timing it
+fn bench_part(
+    solver: Solver,
+    input: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    settings: &Settings,
+) -> PartRun {
+    let text = settings.text;
+    let untimed = |error: anyhow::Error| {
+        if text {
+            let message = format!("Part {}: {:#}", part, error);
+            if is_skipped(&error) {
+                println!("{}", message.yellow());
+            } else {
+                println!("{}", message.red());
             }
-            let output_str = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            extract_part_result(&output_str, part)
         }
-        Err(e) => {
-            eprintln!("Failed to execute command: {}", e);
-            std::process::exit(1);
+        PartRun {
+            year,
+            day,
+            part,
+            answer: Err(error),
+            elapsed: Duration::ZERO,
+            stats: None,
+        }
+    };
+    // First run to verify the solution works
+    if text {
+        println!("Verifying part {}...", part);
+    }
+    let initial_result = match run_solution(solver, input) {
+        Ok(answer) => answer,
+        Err(e) => return untimed(e),
+    };
+    if text {
+        println!(
+            "Initial run successful, result: {}",
+            inline(&initial_result)
+        );
+        println!(
+            "Starting benchmark with up to {} samples...",
+            settings.config.max_samples
+        );
+    }
+
+    let (result, stats) = benchmark(|| run_solution(solver, input), &settings.config);
+    let result = match result {
+        Ok(answer) => answer,
+        Err(e) => return untimed(e),
+    };
+    if text {
+        print_benchmark(part, &result, &stats);
+        if let Some(baseline) = &settings.baseline {
+            match baseline.get(year, day, part) {
+                // a legacy record of unknown answer may have timed another day
+                Some(record) if record.is_verified() => {
+                    let comparison = Comparison::of(&record.stats, &stats);
+                    print_comparison(record, &comparison, settings.threshold);
+                    if record
+                        .answer
+                        .as_ref()
+                        .is_some_and(|answer| *answer != result)
+                    {
+                        println!(
+                            "  {}",
+                            format!(
+                                "answer changed from {}",
+                                record.answer.as_ref().map(cell).unwrap_or_default()
+                            )
+                            .red()
+                        );
+                    }
+                }
+                Some(_) => println!("  no baseline, only a legacy record of unknown answer"),
+                None => println!("  no baseline"),
+            }
         }
     }
+    if settings.store {
+        store_benchmark(
+            &workspace_dir(),
+            year,
+            day,
+            part,
+            result.clone(),
+            stats.clone(),
+        )
+        .unwrap_or_else(|e| fail(&format!("{:#}", e)));
+    }
+    PartRun {
+        year,
+        day,
+        part,
+        answer: Ok(result),
+        elapsed: Duration::from_nanos(stats.mean_ns as u64),
+        stats: Some(stats),
+    }
 }
 
 fn main() {
-    let Cli { _subcommand, day_arg, all, store } = Cli::parse();
-
-    let day = parse_day(&day_arg).unwrap();
-    let year = &_subcommand;
+    let Cli {
+        year,
+        day_arg,
+        all,
+        part,
+        store,
+        input,
+        format,
+        samples,
+        max_time,
+        compare,
+        threshold,
+        budget,
+    } = Cli::parse();
+    let text = format == Format::Text;
+    if compare && !text {
+        fail("--compare only works with --format text");
+    }
+    // load the baseline before --store overwrites it
+    let baseline = compare
+        .then(|| Benchmarks::load(&workspace_dir()).unwrap_or_else(|e| fail(&format!("{:#}", e))));
+    let settings = Settings {
+        config: BenchConfig {
+            max_samples: samples.max(1),
+            max_time: Duration::from_secs_f64(max_time.max(0.0)),
+            ..BenchConfig::default()
+        },
+        store,
+        text,
+        baseline,
+        threshold,
+    };
 
-    println!("\n{}", format!("Day {:02}", day).bright_green().bold());
-    println!("{}", "-".repeat(6).bright_black());
+    let year = parse_year(&year).unwrap_or_else(|e| fail(&e));
+    let registry =
+        registry(year).unwrap_or_else(|| fail(&format!("Year {} is not in the workspace", year)));
+    let dir = year_dir(year);
 
     let total_start = Instant::now();
+    let mut skipped = Vec::new();
+    let runs = match day_arg {
+        Some(day_arg) => {
+            let day = parse_day(&day_arg).unwrap_or_else(|e| fail(&e.to_string()));
+            let solutions = registry
+                .get(day)
+                .unwrap_or_else(|| fail(&format!("Day {} of {} is not implemented", day, year)));
+            let input = input
+                .unwrap_or_default()
+                .load(&dir, year, day)
+                .unwrap_or_else(|e| fail(&format!("{:#}", e)));
+            let runs = bench_day(solutions, &input, part.unwrap_or(1), all, &settings);
+            if let Some(run) = runs.iter().find(|run| run.answer.is_err()) {
+                fail(&format!(
+                    "Error running day {} part {} in year {}: {:#}",
+                    run.day,
+                    run.part,
+                    year,
+                    run.answer.as_ref().unwrap_err()
+                ));
+            }
+            runs
+        }
+        None => {
+            if input.is_some() {
+                fail("--input needs a single day");
+            }
+            let (runs, missing) = bench_year(&registry, &dir, part, &settings);
+            skipped.extend(missing);
+            runs
+        }
+    };
 
-    // First run to verify the solution works
-    println!("Verifying solution...");
-    let initial_result = run_solution(year, day, 1);
-    println!("Initial run successful, result: {}", initial_result);
-    println!("Starting benchmark with {} samples...", SAMPLE_SIZE);
-
-    // Run Part 1 benchmark
-    let (result, duration) = benchmark(|| run_solution(year, day, 1), SAMPLE_SIZE);
-    print_benchmark(day, 1, &result, duration);
-    if store {
-        store_benchmark(day, 1, result, duration);
-    }
-
-    // Run Part 2 if --all is specified
-    if all {
-        println!("\nStarting Part 2...");
-        let initial_result = run_solution(year, day, 2);
-        println!("Initial Part 2 run successful, result: {}", initial_result);
-        println!("Starting Part 2 benchmark with {} samples...", SAMPLE_SIZE);
-        
-        let (result, duration) = benchmark(|| run_solution(year, day, 2), SAMPLE_SIZE);
-        print_benchmark(day, 2, &result, duration);
+    if text {
+        let total_duration = total_start.elapsed();
+        println!(
+            "\nTotal (Run): {}\n",
+            format!("{:.2?}", total_duration).bright_blue()
+        );
         if store {
-            store_benchmark(day, 2, result, duration);
+            println!("{}", "Stored updated benchmarks.".bright_green());
         }
+    } else {
+        print_runs(&runs, format).unwrap_or_else(|e| fail(&format!("{:#}", e)));
+    }
+    // unsolved parts are skipped, while errors and panics fail the run once every day is timed
+    let mut failed = Vec::new();
+    for run in &runs {
+        if let Err(e) = &run.answer {
+            let message = format!("day {} part {}: {:#}", run.day, run.part, e);
+            if is_skipped(e) {
+                skipped.push(message);
+            } else {
+                failed.push(message);
+            }
+        }
+    }
+    // on stderr, to keep json and csv output parseable
+    for skip in &skipped {
+        eprintln!("{}", format!("Skipped {}", skip).yellow());
+    }
+    for failure in &failed {
+        eprintln!("{}", format!("Failed {}", failure).red());
+    }
+    if !failed.is_empty() {
+        fail(&format!("{} of {} parts failed", failed.len(), runs.len()));
     }
 
-    let total_duration = total_start.elapsed();
-    println!(
-        "\nTotal (Run): {}\n",
-        format!("{:.2?}", total_duration).bright_blue()
-    );
+    if let Some(budget) = budget {
+        let over: Vec<String> = runs
+            .iter()
+            .filter(|run| run.elapsed.as_secs_f64() * 1000.0 > budget)
+            .map(|run| {
+                format!(
+                    "day {} part {} took {}",
+                    run.day,
+                    run.part,
+                    format_duration(run.elapsed.as_nanos() as f64)
+                )
+            })
+            .collect();
+        if !over.is_empty() {
+            fail(&format!(
+                "Over the {}ms budget: {}",
+                budget,
+                over.join(", ")
+            ));
+        }
+    }
+}
 
-    if store {
-        println!("{}", "Stored updated benchmarks.".bright_green());
+/// Benchmarks every registered day with both parts, unless `part` picks one.
+///
+/// Days without input are left out and returned as the reasons they were skipped.
+/// Parts that error, panic or aren't solved yet are returned with their error.
+fn bench_year(
+    registry: &Registry,
+    dir: &Path,
+    part: Option<u8>,
+    settings: &Settings,
+) -> (Vec<PartRun>, Vec<String>) {
+    let mut runs = Vec::new();
+    let mut skipped = Vec::new();
+    for solutions in registry.days() {
+        let input = match InputSource::Default.load(dir, registry.year(), solutions.day) {
+            Ok(input) => input,
+            Err(e) => {
+                skipped.push(format!("day {}: {:#}", solutions.day, e));
+                continue;
+            }
+        };
+        let all = part.is_none();
+        runs.extend(bench_day(
+            solutions,
+            &input,
+            part.unwrap_or(1),
+            all,
+            settings,
+        ));
+    }
+    (runs, skipped)
+}
+
+/// Benchmarks `part` of a day, then part 2 as well if `all` is set
+fn bench_day(
+    solutions: &Day,
+    input: &str,
+    part: u8,
+    all: bool,
+    settings: &Settings,
+) -> Vec<PartRun> {
+    let (year, day) = (solutions.year, solutions.day);
+    if settings.text {
+        println!("\n{}", format!("Day {:02}", day).bright_green().bold());
+        println!("{}", "-".repeat(6).bright_black());
     }
-} 
\ No newline at end of file
+
+    let mut runs = vec![match part {
+        1 => bench_part(solutions.part1, input, year, day, 1, settings),
+        _ => match solutions.part2 {
+            Some(solver) => bench_part(solver, input, year, day, 2, settings),
+            None => fail(&format!("Part 2 of day {} is not implemented", day)),
+        },
+    }];
+
+    // Run Part 2 as well if --all is specified
+    if all && part == 1 {
+        match solutions.part2 {
+            Some(solver) => {
+                if settings.text {
+                    println!("\nStarting Part 2...");
+                }
+                runs.push(bench_part(solver, input, year, day, 2, settings));
+            }
+            None if settings.text => println!("\nPart 2 of day {} is not implemented", day),
+            None => {}
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use aoc_lib::input::default_path;
+    use aoc_lib::runner::Unsolved;
+    use aoc_lib::Solution;
+    use std::fs;
+
+    struct Solved;
+
+    impl Solution for Solved {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+        const PART2: bool = false;
+
+        fn part1(input: &str) -> Result<impl Into<Answer>> {
+            Ok(input.trim().len())
+        }
+    }
+
+    struct Scaffolded;
+
+    impl Solution for Scaffolded {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 2;
+
+        fn part1(_input: &str) -> Result<impl Into<Answer>> {
+            Err::<Answer, _>(Unsolved.into())
+        }
+
+        fn part2(_input: &str) -> Result<impl Into<Answer>> {
+            Err::<Answer, _>(Unsolved.into())
+        }
+    }
+
+    struct Broken;
+
+    impl Solution for Broken {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 3;
+
+        fn part1(_input: &str) -> Result<impl Into<Answer>> {
+            Err::<Answer, _>(anyhow!("bad input"))
+        }
+
+        fn part2(input: &str) -> Result<impl Into<Answer>> {
+            Ok(input.as_bytes()[10])
+        }
+    }
+
+    struct WithoutInput;
+
+    impl Solution for WithoutInput {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 4;
+        const PART2: bool = false;
+
+        fn part1(_input: &str) -> Result<impl Into<Answer>> {
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_bench_year_skips_unsolved_and_failed_parts() {
+        let dir = env::temp_dir().join(format!("aoc-test-cargo-time-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for day in 1..=3 {
+            let path = default_path(&dir, 2015, day);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "abc\n").unwrap();
+        }
+        let registry = Registry::new(2015)
+            .register::<Solved>()
+            .register::<Scaffolded>()
+            .register::<Broken>()
+            .register::<WithoutInput>();
+        let settings = Settings {
+            config: BenchConfig {
+                max_samples: 3,
+                max_time: Duration::from_millis(10),
+                min_sample_time: Duration::ZERO,
+            },
+            store: false,
+            text: false,
+            baseline: None,
+            threshold: 10.0,
+        };
+
+        let (runs, skipped) = bench_year(&registry, &dir, None, &settings);
+        let statuses: Vec<(u8, u8, &str)> = runs
+            .iter()
+            .map(|run| (run.day, run.part, run.status()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (1, 1, "ok"),
+                (2, 1, "unsolved"),
+                (2, 2, "unsolved"),
+                (3, 1, "error"),
+                (3, 2, "panic")
+            ]
+        );
+        assert_eq!(runs[0].answer.as_ref().unwrap(), &Answer::from(3));
+        assert_eq!(runs[0].stats.as_ref().unwrap().samples, 3);
+        assert_eq!(skipped.len(), 1);
+        assert!(skipped[0].starts_with("day 4: "));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
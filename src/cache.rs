@@ -0,0 +1,417 @@
+use anyhow::{bail, Context, Result};
+use aoc_lib::input::default_path;
+use aoc_lib::Registry;
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Environment variable holding the adventofcode.com session cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding the site to download from, e.g. a local stand-in
+pub const URL_VAR: &str = "AOC_URL";
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// Where inputs and puzzle statements come from when they aren't cached yet
+pub trait Backend {
+    /// Returns the puzzle input of a day
+    fn input(&self, year: u16, day: u8) -> Result<String>;
+
+    /// Returns the puzzle statement of a day as markdown
+    fn puzzle(&self, year: u16, day: u8) -> Result<String>;
+}
+
+/// Copies inputs and statements from a directory laid out like the cache, e.g. another checkout
+pub struct FixtureDir(pub PathBuf);
+
+impl Backend for FixtureDir {
+    fn input(&self, year: u16, day: u8) -> Result<String> {
+        // `AOC_INPUT_DIR` only moves our own inputs, the directory keeps the cache layout
+        let path = self
+            .0
+            .join(year.to_string())
+            .join("input")
+            .join(format!("day{}.in", day));
+        read(&path)
+    }
+
+    fn puzzle(&self, year: u16, day: u8) -> Result<String> {
+        read(&Cache::new(&self.0).puzzle(year, day))
+    }
+}
+
+/// Downloads from adventofcode.com, or a stand-in serving the same paths, with `curl`
+pub struct Http {
+    base_url: String,
+    session: String,
+}
+
+impl Http {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Http {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Reads the session from `AOC_SESSION`, or `~/.adventofcode.session` as the old
+    /// `aoc` CLI did, and the site from `AOC_URL`
+    pub fn from_env() -> Result<Self> {
+        let session = match env::var(SESSION_VAR) {
+            Ok(session) => session,
+            Err(_) => {
+                let home = env::var("HOME").context("Neither AOC_SESSION nor HOME is set")?;
+                let path = Path::new(&home).join(".adventofcode.session");
+                fs::read_to_string(&path).with_context(|| {
+                    format!(
+                        "Set AOC_SESSION or write the session cookie to {}",
+                        path.display()
+                    )
+                })?
+            }
+        };
+        let base_url = env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_string());
+        Ok(Http::new(&base_url, &session))
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        // the session goes through a config on stdin, as arguments show up in the process list
+        let mut curl = Command::new("curl")
+            .args(["--fail", "--silent", "--show-error", "--location"])
+            .args(["--user-agent", "aoc workspace input cache (curl)"])
+            .args(["--config", "-"])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Failed to run curl")?;
+        let config = format!(
+            "cookie = \"session={}\"\n",
+            self.session.replace('\\', "\\\\").replace('"', "\\\"")
+        );
+        curl.stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(config.as_bytes())
+            .context("Failed to pass the session to curl")?;
+        let output = curl.wait_with_output().context("Failed to run curl")?;
+        if !output.status.success() {
+            bail!(
+                "Failed to download {}: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        String::from_utf8(output.stdout).with_context(|| format!("{} is not UTF-8", url))
+    }
+}
+
+impl Backend for Http {
+    fn input(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    fn puzzle(&self, year: u16, day: u8) -> Result<String> {
+        let html = self.get(&format!("/{}/day/{}", year, day))?;
+        Ok(html_to_markdown(&html))
+    }
+}
+
+/// The state of a cached file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cached {
+    Present,
+    Empty,
+    Missing,
+}
+
+impl Cached {
+    fn of(path: &Path) -> Self {
+        match fs::metadata(path) {
+            Ok(meta) if meta.len() > 0 => Cached::Present,
+            Ok(_) => Cached::Empty,
+            Err(_) => Cached::Missing,
+        }
+    }
+}
+
+/// The cache of inputs, examples and puzzle statements, laid out per year as
+/// `input/dayN.in`, `examples/dayN-M.in` and `puzzles/dayN.md`
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    /// Opens the cache of a workspace
+    pub fn new(root: &Path) -> Self {
+        Cache {
+            root: root.to_path_buf(),
+        }
+    }
+
+    /// Returns the directory of a year, which also holds its answers and benchmarks
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string())
+    }
+
+    /// Returns the input file of a day, honouring `AOC_INPUT_DIR`
+    pub fn input(&self, year: u16, day: u8) -> PathBuf {
+        default_path(&self.year_dir(year), year, day)
+    }
+
+    /// Returns the puzzle statement of a day
+    pub fn puzzle(&self, year: u16, day: u8) -> PathBuf {
+        self.year_dir(year)
+            .join("puzzles")
+            .join(format!("day{}.md", day))
+    }
+
+    /// Returns the directory holding a year's examples
+    pub fn examples(&self, year: u16) -> PathBuf {
+        self.year_dir(year).join("examples")
+    }
+
+    /// Lists the registered days whose input is missing or empty
+    pub fn missing_inputs(&self, registry: &Registry) -> Vec<(u8, Cached)> {
+        registry
+            .days()
+            .map(|day| (day.day, Cached::of(&self.input(day.year, day.day))))
+            .filter(|&(_, state)| state != Cached::Present)
+            .collect()
+    }
+
+    /// Fetches the input and statement of a day that aren't cached yet, or all of
+    /// them with `force`, and returns the files written
+    pub fn fetch(
+        &self,
+        backend: &dyn Backend,
+        year: u16,
+        day: u8,
+        force: bool,
+    ) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        let input = self.input(year, day);
+        if force || Cached::of(&input) != Cached::Present {
+            let text = backend.input(year, day)?;
+            if text.trim().is_empty() {
+                bail!("Downloaded input of {} day {} is empty", year, day);
+            }
+            written.push(write(&input, &text)?);
+        }
+        let puzzle = self.puzzle(year, day);
+        if force || Cached::of(&puzzle) != Cached::Present {
+            written.push(write(&puzzle, &backend.puzzle(year, day)?)?);
+        }
+        Ok(written)
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read file: {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<PathBuf> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    }
+    fs::write(path, contents)
+        .with_context(|| format!("Failed to write file: {}", path.display()))?;
+    Ok(path.to_path_buf())
+}
+
+/// Converts the `<article>` sections of a puzzle page to markdown, in the style of the
+/// statements already cached: fenced `<pre>` blocks, backticked `<code>` and starred `<em>`
+pub fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let body = &rest[start..];
+        let end = body.find("</article>").unwrap_or(body.len());
+        convert(&body[..end], &mut out);
+        rest = &body[end..];
+    }
+    let mut markdown = out.trim().to_string();
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown + "\n"
+}
+
+fn convert(html: &str, out: &mut String) {
+    let mut in_pre = false;
+    let mut rest = html;
+    while let Some(open) = rest.find('<') {
+        push_text(&rest[..open], in_pre, out);
+        let Some(close) = rest[open..].find('>') else {
+            break;
+        };
+        let tag = &rest[open + 1..open + close];
+        let name = tag
+            .trim_start_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+        let closing = tag.starts_with('/');
+        match (name, closing) {
+            ("h2", false) => out.push_str("\n\n\\"),
+            ("h2", true) => out.push_str("\n----------\n\n"),
+            ("p", false) | ("ul", true) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("\n* "),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("\n\n```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            _ => {}
+        }
+        rest = &rest[open + close + 1..];
+    }
+    push_text(rest, in_pre, out);
+}
+
+fn push_text(text: &str, in_pre: bool, out: &mut String) {
+    let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    if in_pre {
+        out.push_str(&text);
+    } else {
+        out.push_str(&text.replace('\n', " "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_lib::input::INPUT_DIR_VAR;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::thread;
+
+    const PAGE: &str =
+        "<html><body><main><article class=\"day-desc\"><h2>--- Day 1: Not Quite Lisp ---</h2>\
+<p>For <em>example</em>:</p><pre><code>(())\n<em>()()</em>\n</code></pre>\
+<ul><li><code>(())</code> results in floor <code>0</code>.</li></ul></article>\
+<p>To begin, get your puzzle input.</p></main></body></html>";
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn test_html_to_markdown() {
+        assert_eq!(
+            html_to_markdown(PAGE),
+            "\\--- Day 1: Not Quite Lisp ---\n----------\n\nFor *example*:\n\n```\n(())\n()()\n```\n\n* `(())` results in floor `0`.\n"
+        );
+    }
+
+    #[test]
+    fn test_fetch_from_fixture_dir() {
+        let _env = crate::env_lock();
+        let fixtures = temp_root("aoc-test-cache-fixtures");
+        let root = temp_root("aoc-test-cache-fixture-target");
+        write(&fixtures.join("2015/input/day2.in"), "2x3x4\n").unwrap();
+        write(&Cache::new(&fixtures).puzzle(2015, 2), "--- Day 2 ---\n").unwrap();
+
+        let cache = Cache::new(&root);
+        let backend = FixtureDir(fixtures.clone());
+        assert_eq!(cache.fetch(&backend, 2015, 2, false).unwrap().len(), 2);
+        assert_eq!(read(&cache.input(2015, 2)).unwrap(), "2x3x4\n");
+        assert!(cache.fetch(&backend, 2015, 2, false).unwrap().is_empty());
+        assert_eq!(cache.fetch(&backend, 2015, 2, true).unwrap().len(), 2);
+        assert!(cache.fetch(&backend, 2015, 3, false).is_err());
+
+        fs::remove_dir_all(fixtures).unwrap();
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_fixture_dir_ignores_input_dir() {
+        let _env = crate::env_lock();
+        let fixtures = temp_root("aoc-test-cache-fixtures-env");
+        let inputs = temp_root("aoc-test-cache-inputs-env");
+        write(&fixtures.join("2015/input/day2.in"), "2x3x4\n").unwrap();
+        write(&inputs.join("2015/day2.in"), "1x1x10\n").unwrap();
+
+        env::set_var(INPUT_DIR_VAR, &inputs);
+        let fetched = FixtureDir(fixtures.clone()).input(2015, 2);
+        let cached = Cache::new(&fixtures).input(2015, 2);
+        env::remove_var(INPUT_DIR_VAR);
+
+        assert_eq!(fetched.unwrap(), "2x3x4\n");
+        assert_eq!(cached, inputs.join("2015/day2.in"));
+        fs::remove_dir_all(fixtures).unwrap();
+        fs::remove_dir_all(inputs).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_over_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut cookies = Vec::new();
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let mut path = String::new();
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(request) = line.strip_prefix("GET ") {
+                        path = request.split(' ').next().unwrap().to_string();
+                    }
+                    if let Some(cookie) = line.strip_prefix("Cookie: ") {
+                        cookies.push(cookie.to_string());
+                    }
+                }
+                let body = if path.ends_with("/input") {
+                    "(()(()(\n"
+                } else {
+                    PAGE
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            cookies
+        });
+
+        let _env = crate::env_lock();
+        let root = temp_root("aoc-test-cache-http");
+        let cache = Cache::new(&root);
+        cache
+            .fetch(&Http::new(&base_url, "abc123\n"), 2015, 1, false)
+            .unwrap();
+        assert_eq!(read(&cache.input(2015, 1)).unwrap(), "(()(()(\n");
+        assert!(read(&cache.puzzle(2015, 1))
+            .unwrap()
+            .starts_with("\\--- Day 1"));
+        assert_eq!(server.join().unwrap(), vec!["session=abc123"; 2]);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,337 @@
+use anyhow::{Context, Result};
+use aoc_lib::verify::DayAnswers;
+use aoc_lib::Answer;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An example input from a puzzle statement, with the answers it is given for
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub answers: DayAnswers,
+}
+
+/// Extracts the examples of a cached puzzle statement.
+///
+/// Each part's example is the first code block of its section introduced as an
+/// example, by a paragraph mentioning one or supposing an input, or else its first
+/// code block. Blocks the statement calls wrapped for legibility are joined back
+/// onto one line, as in the real input. The answer is the first highlighted value
+/// of the last paragraph highlighting any, since statements close on the answer
+/// and sometimes contrast it with a wrong one. Older statements highlight nothing,
+/// so then the last value quoted after the example, outside the puzzle's question,
+/// is the answer. Part two often reuses the example of part one, so its answer
+/// goes to that example when it has no block of its own. Examples without any
+/// answer are left out.
+pub fn extract(markdown: &str) -> Vec<Example> {
+    let (part1, part2) = match markdown.split_once("--- Part Two ---") {
+        Some((part1, part2)) => (part1, Some(part2)),
+        None => (markdown, None),
+    };
+
+    let mut examples: Vec<Example> = Vec::new();
+    if let Some((input, end)) = example_block(part1) {
+        examples.push(Example {
+            input,
+            answers: DayAnswers {
+                part1: answer(part1, end),
+                part2: None,
+            },
+        });
+    }
+    if let Some(part2) = part2 {
+        match (example_block(part2), examples.first_mut()) {
+            (Some((input, end)), _) => examples.push(Example {
+                input,
+                answers: DayAnswers {
+                    part1: None,
+                    part2: answer(part2, end),
+                },
+            }),
+            (None, Some(first)) => first.answers.part2 = answer(part2, 0),
+            (None, None) => {}
+        }
+    }
+    examples.retain(|e| e.answers.part1.is_some() || e.answers.part2.is_some());
+    examples
+}
+
+/// A fenced or `<pre><code>` block, spanning `start..end` of the text it was found in
+struct Block {
+    start: usize,
+    end: usize,
+    contents: String,
+}
+
+/// Returns the example input of a section and where its block ends, see [`extract`]
+fn example_block(text: &str) -> Option<(String, usize)> {
+    let blocks = code_blocks(text);
+    let block = blocks
+        .iter()
+        .find(|block| {
+            let intro = last_paragraph(&text[..block.start]).to_lowercase();
+            intro.contains("example") || intro.contains("suppose")
+        })
+        .or(blocks.first())?;
+    let note = text[block.end..].trim_start().split("\n\n").next()?;
+    if note.contains("wrapped") {
+        Some((block.contents.lines().collect::<String>() + "\n", block.end))
+    } else {
+        Some((block.contents.clone(), block.end))
+    }
+}
+
+/// Returns the non-empty fenced and `<pre><code>` blocks in `text`
+fn code_blocks(text: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut offset = 0;
+    loop {
+        let rest = &text[offset..];
+        let fenced = rest.find("```");
+        let html = rest.find("<pre><code>");
+        let (start, open, close) = match (fenced, html) {
+            (Some(f), Some(h)) if h < f => (h, "<pre><code>", "</code></pre>"),
+            (Some(f), _) => (f, "```", "```"),
+            (None, Some(h)) => (h, "<pre><code>", "</code></pre>"),
+            (None, None) => break,
+        };
+        let mut body_start = start + open.len();
+        // A fence opens with the rest of its line, e.g. a language tag
+        if open == "```" {
+            body_start = rest[body_start..]
+                .find('\n')
+                .map_or(rest.len(), |i| body_start + i + 1);
+        }
+        let Some(len) = rest[body_start..].find(close) else {
+            break;
+        };
+        let block = rest[body_start..body_start + len].trim_end();
+        let end = body_start + len + close.len();
+        if !block.trim().is_empty() {
+            blocks.push(Block {
+                start: offset + start,
+                end: offset + end,
+                contents: unescape(block) + "\n",
+            });
+        }
+        offset += end;
+    }
+    blocks
+}
+
+/// Returns the answer a section gives for its example ending at `end`, see [`extract`]
+fn answer(text: &str, end: usize) -> Option<Answer> {
+    let paragraphs = prose(text);
+    let value = paragraphs
+        .iter()
+        .rev()
+        .find_map(|paragraph| highlights(paragraph).into_iter().next())
+        .or_else(|| {
+            prose(&text[end..])
+                .iter()
+                .rev()
+                .filter(|paragraph| !paragraph.trim_end().ends_with('?'))
+                .find_map(|paragraph| {
+                    paragraph
+                        .split('`')
+                        .skip(1)
+                        .step_by(2)
+                        .filter(|value| is_value(value))
+                        .last()
+                })
+        })?;
+    Some(Answer::from(unescape(value).as_str()))
+}
+
+/// Whether quoted text could be an answer rather than a calculation or instruction
+fn is_value(text: &str) -> bool {
+    text.parse::<i64>().is_ok()
+        || (!text.is_empty() && text.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Returns the paragraphs of `text` without its blocks, so their contents don't
+/// split or end paragraphs
+fn prose(text: &str) -> Vec<&str> {
+    let mut paragraphs = Vec::new();
+    let mut offset = 0;
+    for block in code_blocks(text) {
+        paragraphs.extend(text[offset..block.start].split("\n\n"));
+        offset = block.end;
+    }
+    paragraphs.extend(text[offset..].split("\n\n"));
+    paragraphs
+}
+
+/// Returns the paragraph `text` ends with
+fn last_paragraph(text: &str) -> &str {
+    let text = text.trim_end();
+    text.rfind("\n\n").map_or(text, |i| &text[i + 2..])
+}
+
+/// Returns the values highlighted as `` *`x`* `` or `` `*x*` ``, in order
+fn highlights(text: &str) -> Vec<&str> {
+    let mut values = Vec::new();
+    let mut rest = text;
+    loop {
+        let start = match (rest.find("*`"), rest.find("`*")) {
+            (Some(a), Some(b)) => a.min(b),
+            (Some(a), None) => a,
+            (None, Some(b)) => b,
+            (None, None) => break,
+        };
+        let close = if rest[start..].starts_with("*`") {
+            "`*"
+        } else {
+            "*`"
+        };
+        let body = &rest[start + 2..];
+        let Some(len) = body.find(close) else {
+            break;
+        };
+        values.push(&body[..len]);
+        rest = &body[len + close.len()..];
+    }
+    values
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Writes a day's examples as `dayN-M.in`, with their answers in `dayN-M.json`,
+/// and returns the files written
+pub fn write_fixtures(dir: &Path, day: u8, examples: &[Example]) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    let mut written = Vec::new();
+    for (i, example) in examples.iter().enumerate() {
+        let stem = format!("day{}-{}", day, i + 1);
+        let input = dir.join(format!("{}.in", stem));
+        fs::write(&input, &example.input)
+            .with_context(|| format!("Failed to write file: {}", input.display()))?;
+        let answers = dir.join(format!("{}.json", stem));
+        let json = serde_json::to_string_pretty(&example.answers)? + "\n";
+        fs::write(&answers, json)
+            .with_context(|| format!("Failed to write file: {}", answers.display()))?;
+        written.extend([input, answers]);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const PUZZLE: &str = "\\--- Day 7: Laboratories ---
+----------
+
+For example:
+
+```
+.......S.......
+...^.^...
+
+```
+
+In this example, a tachyon beam is split a total of *`21`* times.
+
+--- Part Two ---
+
+With the same example, there are `*40*` timelines, not `*4*` &lt;- see above.
+";
+
+    #[test]
+    fn test_extract_shared_example() {
+        let examples = extract(PUZZLE);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].input, ".......S.......\n...^.^...\n");
+        assert_eq!(examples[0].answers.part1, Some(Answer::Unsigned(21)));
+        assert_eq!(examples[0].answers.part2, Some(Answer::Unsigned(40)));
+    }
+
+    #[test]
+    fn test_extract_separate_examples() {
+        let puzzle = "<pre><code>R2, L3\n</code></pre>\nYou end up *`5`* away.\n\
+--- Part Two ---\n```\nR8, R4\n```\nThe first place is `*4*` blocks away.";
+        let examples = extract(puzzle);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input, "R2, L3\n");
+        assert_eq!(examples[0].answers.part2, None);
+        assert_eq!(examples[1].input, "R8, R4\n");
+        assert_eq!(examples[1].answers.part2, Some(Answer::Unsigned(4)));
+    }
+
+    #[test]
+    fn test_extract_wrapped_example() {
+        let puzzle = "For example:\n\n```\n1-2,3-4,\n5-6\n```\n\n(The ranges are wrapped here \
+for legibility.)\n\nThis produces `*7*`.";
+        let examples = extract(puzzle);
+        assert_eq!(examples[0].input, "1-2,3-4,5-6\n");
+        assert_eq!(examples[0].answers.part1, Some(Answer::Unsigned(7)));
+    }
+
+    #[test]
+    fn test_extract_introduced_example() {
+        let puzzle = "Picture a keypad:\n\n```\n1 2 3\n```\n\nSuppose your instructions are:\n\n\
+```\nULL\nRRDDD\n```\n\n* The first button is `1`.\n\nSo, in this example, the code is `19`.";
+        let examples = extract(puzzle);
+        assert_eq!(examples[0].input, "ULL\nRRDDD\n");
+        assert_eq!(examples[0].answers.part1, Some(Answer::Unsigned(19)));
+    }
+
+    #[test]
+    fn test_extract_unhighlighted_answer() {
+        // 2015 day 13, quoting values while working through the example
+        let puzzle = "For example, suppose you have only four attendees planned:\n\n```\n\
+Alice would gain 54 happiness units by sitting next to Bob.\n```\n\nThen, if you seat Alice next \
+to David, Alice would lose `2` happiness units, for a total change of `44`.\n\n```\n\
+     +41 +46\n+55   David    -2\n```\n\nAfter trying every other seating arrangement in this \
+hypothetical scenario, you find that this one is the most optimal, with a total change in \
+happiness of `330`.\n\nWhat is the *total change in happiness* for the optimal seating \
+arrangement of the actual guest list?\n\n--- Part Two ---\n\nSo, add yourself to the list, and \
+give all happiness relationships that involve you a score of 0.";
+        let examples = extract(puzzle);
+        assert_eq!(
+            examples[0].input,
+            "Alice would gain 54 happiness units by sitting next to Bob.\n"
+        );
+        assert_eq!(examples[0].answers.part1, Some(Answer::Unsigned(330)));
+        assert_eq!(examples[0].answers.part2, None);
+
+        // 2016 day 6, answering in text
+        let puzzle = "For example, suppose you had recorded the following messages:\n\n```\n\
+eedadn\ndrvtee\n\n```\n\nThe most common character in the first column is `e`; in the second, \
+`a`; in the third, `s`, and so on. Combining these characters returns the error-corrected \
+message, `easter`.\n\nGiven the recording in your puzzle input, *what is the error-corrected \
+version* of the message being sent?\n\nTo begin, [get your puzzle input](6/input).";
+        let examples = extract(puzzle);
+        assert_eq!(examples[0].input, "eedadn\ndrvtee\n");
+        assert_eq!(
+            examples[0].answers.part1,
+            Some(Answer::Text("easter".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_extract_without_answers() {
+        assert!(extract("```\n1\n2\n```\n\nWhat is the sum of `x` and `y`?").is_empty());
+    }
+
+    #[test]
+    fn test_write_fixtures() {
+        let dir = env::temp_dir().join(format!("aoc-test-examples-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let written = write_fixtures(&dir, 7, &extract(PUZZLE)).unwrap();
+        assert_eq!(
+            written,
+            vec![dir.join("day7-1.in"), dir.join("day7-1.json")]
+        );
+        let answers: DayAnswers =
+            serde_json::from_str(&fs::read_to_string(dir.join("day7-1.json")).unwrap()).unwrap();
+        assert_eq!(answers.part1, Some(Answer::Unsigned(21)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
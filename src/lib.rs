@@ -0,0 +1,41 @@
+use aoc_lib::Registry;
+use std::path::PathBuf;
+
+pub mod cache;
+pub mod examples;
+pub mod scaffold;
+pub mod status;
+pub mod summary;
+
+/// Returns the registry of every year crate in the workspace
+pub fn registries() -> Vec<Registry> {
+    vec![
+        aoc_2015::registry(),
+        aoc_2016::registry(),
+        aoc_2019::registry(),
+        aoc_2024::registry(),
+        aoc_2025::registry(),
+    ]
+}
+
+/// Looks up the registry for a single year
+pub fn registry(year: u16) -> Option<Registry> {
+    registries().into_iter().find(|r| r.year() == year)
+}
+
+/// Returns the workspace directory
+pub fn workspace_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Returns the directory of a year crate, which solvers read their inputs relative to
+pub fn year_dir(year: u16) -> PathBuf {
+    workspace_dir().join(year.to_string())
+}
+
+/// Held by tests that set `AOC_INPUT_DIR` or resolve input paths, as tests share the environment
+#[cfg(test)]
+pub(crate) fn env_lock() -> std::sync::MutexGuard<'static, ()> {
+    static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
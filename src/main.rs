@@ -0,0 +1,640 @@
+use anyhow::{bail, Context, Result};
+use aoc::cache::{Backend, Cache, Cached, FixtureDir, Http};
+use aoc::examples::{extract, write_fixtures};
+use aoc::scaffold::new_day;
+use aoc::status::{discover, year_status, Discovered, PartStatus};
+use aoc::summary::{markdown, revision, rows, total_ns, update_section};
+use aoc::{registries, registry, workspace_dir, year_dir};
+use aoc_lib::benchmark::{format_duration, Benchmarks};
+use aoc_lib::parallel::default_jobs;
+use aoc_lib::report::Format;
+use aoc_lib::runner::{cell, inline, parse_day, print_runs, run_all, run_part, run_parts, PartRun};
+use aoc_lib::verify::{Answers, Status, ANSWERS_FILE};
+use aoc_lib::{Answer, Day, InputSource, Registry};
+use clap::{Parser, Subcommand};
+use colored::*;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    about = "Run any Advent of Code solution in the workspace"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solutions in-process, or every day of the year with a summary table
+    Run {
+        /// The year, e.g. 2015
+        year: u16,
+
+        /// The day (e.g., day2 or just 2); every registered day when left out
+        day: Option<String>,
+
+        /// Run only this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the input from this file instead, or from stdin when `-`
+        #[arg(long, value_name = "FILE")]
+        input: Option<InputSource>,
+
+        /// How many parts to run at once when running every day [default: number of CPUs]
+        #[arg(long, short)]
+        jobs: Option<usize>,
+
+        /// Print the results as text, json or csv
+        #[arg(long, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check every solved day against the known answers in its year's answers.json
+    Verify {
+        /// Only verify this year
+        year: Option<u16>,
+
+        /// Only verify this day (e.g., day2 or just 2)
+        #[arg(long)]
+        day: Option<String>,
+
+        /// Record the answers of parts that have no known answer yet
+        #[arg(long)]
+        record: bool,
+
+        /// How many parts to run at once [default: number of CPUs]
+        #[arg(long, short)]
+        jobs: Option<usize>,
+    },
+    /// Download a day's input and puzzle statement into the cache, unless already there
+    Fetch {
+        /// The year, e.g. 2015
+        year: u16,
+
+        /// The day (e.g., day2 or just 2); every registered day with a missing input when left out
+        day: Option<String>,
+
+        /// Download again even if the files are cached
+        #[arg(long)]
+        force: bool,
+
+        /// Copy from this directory, laid out like the workspace, instead of downloading
+        #[arg(long, value_name = "DIR")]
+        from: Option<PathBuf>,
+    },
+    /// Extract the examples of cached puzzle statements into test fixtures
+    Examples {
+        /// The year, e.g. 2015
+        year: u16,
+
+        /// The day (e.g., day2 or just 2); every cached statement when left out
+        day: Option<String>,
+    },
+    /// Show a matrix of the implemented, verified and benchmarked parts of every year
+    Status {
+        /// Only show this year
+        year: Option<u16>,
+    },
+    /// Show the stored benchmarks of a year, or write them into its README
+    Bench {
+        /// The year, e.g. 2015
+        year: u16,
+
+        /// Print the table as a markdown section instead
+        #[arg(long)]
+        markdown: bool,
+
+        /// Write the markdown section into the year's README.md
+        #[arg(long)]
+        readme: bool,
+    },
+    /// List the registered days whose input is missing or empty
+    Inputs {
+        /// Only check this year
+        year: Option<u16>,
+    },
+    /// Create a day from the template, and its year crate if needed
+    New {
+        /// The year, e.g. 2015
+        year: u16,
+
+        /// The day (e.g., day2 or just 2)
+        day: String,
+    },
+}
+
+fn lookup(year: u16) -> Result<Registry> {
+    match registry(year) {
+        Some(registry) => Ok(registry),
+        None => {
+            let years: Vec<String> = registries().iter().map(|r| r.year().to_string()).collect();
+            bail!(
+                "Year {} is not in the workspace (have {})",
+                year,
+                years.join(", ")
+            )
+        }
+    }
+}
+
+fn run(
+    year: u16,
+    day_arg: Option<&str>,
+    part: Option<u8>,
+    source: Option<InputSource>,
+    jobs: usize,
+    format: Format,
+) -> Result<()> {
+    let registry = lookup(year)?;
+    let Some(day_arg) = day_arg else {
+        if source.is_some() {
+            bail!("--input needs a single day");
+        }
+        return run_all(&registry, &year_dir(year), part, jobs, format);
+    };
+    let source = source.unwrap_or_default();
+    let day_num = parse_day(day_arg)?;
+    let Some(day) = registry.get(day_num) else {
+        bail!("Day {} of {} is not implemented", day_num, year);
+    };
+
+    let input = source.load(&year_dir(year), year, day_num)?;
+
+    let parts: Vec<u8> = match part {
+        Some(part) => vec![part],
+        None => day.parts().collect(),
+    };
+    let mut runs = Vec::new();
+    for part in parts {
+        let Some(run) = run_part(day, part, &input) else {
+            bail!(
+                "Day {} part {} of {} is not implemented",
+                day_num,
+                part,
+                year
+            );
+        };
+        runs.push(run);
+    }
+
+    if format == Format::Text {
+        println!(
+            "\n{}",
+            format!("{} Day {:02}", year, day_num).bright_green().bold()
+        );
+        println!("{}", "-".repeat(11).bright_black());
+        for run in &runs {
+            let elapsed = format_duration(run.elapsed.as_nanos() as f64);
+            match &run.answer {
+                Ok(answer) => println!(
+                    "Part {}: {} ({})",
+                    run.part,
+                    inline(answer).bright_yellow(),
+                    elapsed.bright_blue()
+                ),
+                Err(e) => println!(
+                    "Part {}: {} ({})",
+                    run.part,
+                    format!("{:#}", e).red(),
+                    elapsed.bright_blue()
+                ),
+            }
+        }
+    } else {
+        print_runs(&runs, format)?;
+    }
+
+    if runs.iter().any(PartRun::failed) {
+        bail!("Day {} of {} failed", day_num, year);
+    }
+    Ok(())
+}
+
+struct Check {
+    run: PartRun,
+    expected: Option<Answer>,
+    status: Status,
+}
+
+fn verify(year: Option<u16>, day_arg: Option<&str>, record: bool, jobs: usize) -> Result<()> {
+    let registries = match year {
+        Some(year) => vec![lookup(year)?],
+        None => registries(),
+    };
+    let day_filter = day_arg.map(parse_day).transpose()?;
+
+    // Run every selected part of every year up front, so slow days overlap
+    let parts: Vec<(&Day, u8)> = registries
+        .iter()
+        .flat_map(|registry| registry.parts())
+        .filter(|(day, _)| day_filter.is_none_or(|n| day.day == n))
+        .collect();
+    let mut runs = run_parts(&parts, year_dir, jobs).into_iter().peekable();
+
+    println!(
+        "{}",
+        format!(
+            "{:<4}  {:>3}  {:>4}  {:<20}  {:<20}  {:<10}  {:>9}",
+            "Year", "Day", "Part", "Answer", "Expected", "Status", "Time"
+        )
+        .bold()
+    );
+
+    let mut checks = Vec::new();
+    for registry in &registries {
+        let path = year_dir(registry.year()).join(ANSWERS_FILE);
+        let mut answers = Answers::load(&path)?;
+        let mut recorded = 0;
+        while let Some(run) = runs.next_if(|run| run.year == registry.year()) {
+            let expected = answers.get(run.day, run.part).cloned();
+            let status = Status::of(&run.answer, expected.as_ref());
+            let check = Check {
+                run,
+                expected,
+                status,
+            };
+            let run = &check.run;
+            let answer = match &run.answer {
+                Ok(answer) => cell(answer),
+                Err(_) if check.status == Status::Skipped => String::new(),
+                Err(_) => "error".to_string(),
+            };
+            let expected = check.expected.as_ref().map(cell).unwrap_or_default();
+            let status = format!("{:<10}", check.status);
+            let status = match check.status {
+                Status::Pass => status.bright_green(),
+                Status::Fail | Status::Regression => status.red().bold(),
+                Status::Unrecorded | Status::Skipped => status.yellow(),
+            };
+            println!(
+                "{:<4}  {:>3}  {:>4}  {:<20}  {:<20}  {}  {:>9}",
+                run.year,
+                run.day,
+                run.part,
+                answer,
+                expected,
+                status,
+                format_duration(run.elapsed.as_nanos() as f64)
+            );
+            if record && check.status == Status::Unrecorded {
+                if let Ok(answer) = &run.answer {
+                    answers.set(run.day, run.part, answer.clone());
+                    recorded += 1;
+                }
+            }
+            checks.push(check);
+        }
+        if recorded > 0 {
+            answers.save(&path)?;
+            println!("Recorded {} new answers in {}", recorded, path.display());
+        }
+    }
+
+    for check in checks.iter().filter(|c| c.status == Status::Skipped) {
+        let run = &check.run;
+        if let Err(e) = &run.answer {
+            println!(
+                "{}",
+                format!("{} day {} part {}: {:#}", run.year, run.day, run.part, e).yellow()
+            );
+        }
+    }
+
+    let failures: Vec<&Check> = checks.iter().filter(|c| c.status.is_failure()).collect();
+    for check in &failures {
+        let run = &check.run;
+        let detail = match (&run.answer, &check.expected) {
+            (Err(e), _) => format!("{:#}", e),
+            (Ok(answer), Some(expected)) => format!("expected {}, got {}", expected, answer),
+            (Ok(_), None) => continue,
+        };
+        println!(
+            "{}",
+            format!("{} day {} part {}: {}", run.year, run.day, run.part, detail).red()
+        );
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    println!(
+        "\n{} passed, {} failed, {} regressed, {} unrecorded, {} skipped",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Regression),
+        count(Status::Unrecorded),
+        count(Status::Skipped)
+    );
+
+    if !failures.is_empty() {
+        bail!(
+            "{} of {} parts did not verify",
+            failures.len(),
+            checks.len()
+        );
+    }
+    Ok(())
+}
+
+fn new(year: u16, day_arg: &str) -> Result<()> {
+    let day = parse_day(day_arg)?;
+    let root = workspace_dir();
+    for path in new_day(&root, year, day)? {
+        let path = path.strip_prefix(&root).unwrap_or(&path);
+        println!("{} {}", "wrote".bright_green(), path.display());
+    }
+    if registry(year).is_none() {
+        println!("Rebuild `aoc` to pick up the new {} crate", year);
+    }
+    Ok(())
+}
+
+fn fetch(year: u16, day_arg: Option<&str>, force: bool, from: Option<PathBuf>) -> Result<()> {
+    let root = workspace_dir();
+    let cache = Cache::new(&root);
+    let days = match day_arg {
+        Some(day_arg) => vec![parse_day(day_arg)?],
+        None => {
+            let days: Vec<u8> = cache
+                .missing_inputs(&lookup(year)?)
+                .into_iter()
+                .map(|(day, _)| day)
+                .collect();
+            if days.is_empty() {
+                println!("Every registered day of {} has its input", year);
+            }
+            days
+        }
+    };
+    let backend: Box<dyn Backend> = match from {
+        Some(dir) => Box::new(FixtureDir(dir)),
+        None => Box::new(Http::from_env()?),
+    };
+
+    for day in days {
+        let written = cache.fetch(backend.as_ref(), year, day, force)?;
+        if written.is_empty() {
+            println!("{} day {} is already cached", year, day);
+        }
+        for path in written {
+            let path = path.strip_prefix(&root).unwrap_or(&path);
+            println!("{} {}", "wrote".bright_green(), path.display());
+        }
+    }
+    Ok(())
+}
+
+fn examples(year: u16, day_arg: Option<&str>) -> Result<()> {
+    let root = workspace_dir();
+    let cache = Cache::new(&root);
+    let days: Vec<u8> = match day_arg {
+        Some(day_arg) => vec![parse_day(day_arg)?],
+        None => (1..=25)
+            .filter(|&day| cache.puzzle(year, day).exists())
+            .collect(),
+    };
+    if days.is_empty() {
+        println!("No puzzle statements of {} are cached", year);
+    }
+
+    for day in days {
+        let path = cache.puzzle(year, day);
+        let markdown = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        let examples = extract(&markdown);
+        if examples.is_empty() {
+            println!(
+                "{} day {:>2}: {}",
+                year,
+                day,
+                "no answered examples".yellow()
+            );
+        }
+        for path in write_fixtures(&cache.examples(year), day, &examples)? {
+            let path = path.strip_prefix(&root).unwrap_or(&path);
+            println!("{} {}", "wrote".bright_green(), path.display());
+        }
+    }
+    Ok(())
+}
+
+fn status(year: Option<u16>) -> Result<()> {
+    let root = workspace_dir();
+    let cache = Cache::new(&root);
+    let benchmarks = Benchmarks::load(&root)?;
+    let mut found = discover(&root)?;
+    if let Some(year) = year {
+        found.retain(|discovered| match discovered {
+            Discovered::Crate(registry) => registry.year() == year,
+            Discovered::Unregistered(y) | Discovered::Foreign(y) => *y == year,
+        });
+        if found.is_empty() {
+            lookup(year)?;
+        }
+    }
+
+    let days: String = (1..=25).map(|day| format!("{:>3}", day)).collect();
+    println!(
+        "{}",
+        format!(
+            "{:<6}{}  {:>5} {:>5} {:>5} {:>5}",
+            "Year", days, "Impl", "Stars", "Bench", "Input"
+        )
+        .bold()
+    );
+    for discovered in &found {
+        let registry = match discovered {
+            Discovered::Crate(registry) => registry,
+            Discovered::Unregistered(year) => {
+                println!(
+                    "{:<6}{}",
+                    year,
+                    "not built into `aoc` yet, rebuild it".yellow()
+                );
+                continue;
+            }
+            Discovered::Foreign(year) => {
+                println!("{:<6}{}", year, "not a Rust crate".dimmed());
+                continue;
+            }
+        };
+        let status = year_status(&cache, &benchmarks, registry)?;
+        let cells: String = status
+            .parts
+            .iter()
+            .map(|parts| format!(" {}{}", symbol(&parts[0]), symbol(&parts[1])))
+            .collect();
+        let implemented = status.count(|p| p.implemented);
+        let without_input = status.count(|p| p.implemented && !p.has_input);
+        println!(
+            "{:<6}{}  {:>5} {:>5} {:>5} {:>5}",
+            status.year,
+            cells,
+            implemented,
+            status.count(|p| p.implemented && p.verified),
+            status.count(|p| p.implemented && p.benchmarked),
+            if without_input == 0 {
+                "ok".to_string()
+            } else {
+                format!("{} !", without_input)
+            }
+        );
+    }
+    println!(
+        "\n{} benchmarked  {} verified  {} implemented  {} missing input  {} not started",
+        "#".bright_green(),
+        "*".bright_yellow(),
+        "o".normal(),
+        "!".red(),
+        ".".dimmed()
+    );
+    Ok(())
+}
+
+fn symbol(part: &PartStatus) -> ColoredString {
+    let symbol = part.symbol().to_string();
+    match part.symbol() {
+        '#' => symbol.bright_green(),
+        '*' => symbol.bright_yellow(),
+        '!' => symbol.red(),
+        '.' => symbol.dimmed(),
+        _ => symbol.normal(),
+    }
+}
+
+fn bench(year: u16, print_markdown: bool, readme: bool) -> Result<()> {
+    let root = workspace_dir();
+    let rows = rows(&Benchmarks::load(&root)?, year);
+
+    if readme {
+        let path = year_dir(year).join("README.md");
+        let text = if path.exists() {
+            fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read file: {}", path.display()))?
+        } else {
+            format!("# Advent of Code {}\n", year)
+        };
+        fs::write(&path, update_section(&text, &markdown(year, &rows)))
+            .with_context(|| format!("Failed to write file: {}", path.display()))?;
+        let path = path.strip_prefix(&root).unwrap_or(&path);
+        println!("{} {}", "wrote".bright_green(), path.display());
+        return Ok(());
+    }
+    if print_markdown {
+        print!("{}", markdown(year, &rows));
+        return Ok(());
+    }
+
+    if rows.is_empty() {
+        println!("No benchmarks of {} are stored yet", year);
+        return Ok(());
+    }
+    println!(
+        "{}",
+        format!(
+            "{:>3}  {:>4}  {:<20}  {:>9}  {:>9}  {:>7}  {}",
+            "Day", "Part", "Answer", "Mean", "Median", "Samples", "Revision"
+        )
+        .bold()
+    );
+    for row in &rows {
+        let answer = match &row.answer {
+            Some(answer) => format!("{:<20}", answer).bright_yellow(),
+            None => format!("{:<20}", "unknown").dimmed(),
+        };
+        println!(
+            "{:>3}  {:>4}  {}  {}  {:>9}  {:>7}  {}",
+            row.day,
+            row.part,
+            answer,
+            format!("{:>9}", format_duration(row.mean_ns)).bright_blue(),
+            row.median_ns.map(format_duration).unwrap_or_default(),
+            row.samples,
+            revision(row)
+        );
+    }
+    println!(
+        "{:>3}  {:>4}  {:<20}  {}",
+        "",
+        "",
+        "Total".bold(),
+        format!("{:>9}", format_duration(total_ns(&rows))).bright_blue()
+    );
+    Ok(())
+}
+
+fn inputs(year: Option<u16>) -> Result<()> {
+    let registries = match year {
+        Some(year) => vec![lookup(year)?],
+        None => registries(),
+    };
+    let cache = Cache::new(&workspace_dir());
+    let mut missing = 0;
+    for registry in &registries {
+        for (day, state) in cache.missing_inputs(registry) {
+            missing += 1;
+            let state = match state {
+                Cached::Empty => "empty",
+                _ => "missing",
+            };
+            println!(
+                "{} day {:>2}: input {} ({})",
+                registry.year(),
+                day,
+                state.red(),
+                cache.input(registry.year(), day).display()
+            );
+        }
+    }
+    if missing == 0 {
+        println!("{}", "Every registered day has its input".bright_green());
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            jobs,
+            format,
+        } => run(
+            year,
+            day.as_deref(),
+            part,
+            input,
+            jobs.unwrap_or_else(default_jobs),
+            format,
+        ),
+        Command::Verify {
+            year,
+            day,
+            record,
+            jobs,
+        } => verify(
+            year,
+            day.as_deref(),
+            record,
+            jobs.unwrap_or_else(default_jobs),
+        ),
+        Command::Fetch {
+            year,
+            day,
+            force,
+            from,
+        } => fetch(year, day.as_deref(), force, from),
+        Command::Examples { year, day } => examples(year, day.as_deref()),
+        Command::Status { year } => status(year),
+        Command::Bench {
+            year,
+            markdown,
+            readme,
+        } => bench(year, markdown, readme),
+        Command::Inputs { year } => inputs(year),
+        Command::New { year, day } => new(year, &day),
+    }
+}
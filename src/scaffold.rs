@@ -0,0 +1,333 @@
+use anyhow::{bail, Context, Result};
+use aoc_lib::input::default_path;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Creates a day from the templates below, and its year crate if that doesn't exist yet.
+///
+/// `root` is the workspace directory. Returns every file created or changed.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    if !(2015..=2099).contains(&year) {
+        bail!("Invalid year: {}", year);
+    }
+    if !(1..=25).contains(&day) {
+        bail!("Invalid day: {} (expected 1 to 25)", day);
+    }
+
+    let year_dir = root.join(year.to_string());
+    let mut touched = Vec::new();
+    if !year_dir.join("Cargo.toml").exists() {
+        if year_dir.exists() {
+            bail!(
+                "{} exists but is not a Rust crate, move it aside first",
+                year_dir.display()
+            );
+        }
+        touched.extend(new_year(root, year)?);
+    }
+
+    let day_dir = year_dir.join("src").join(format!("day{}", day));
+    if day_dir.exists() {
+        bail!("Day {} of {} already exists", day, year);
+    }
+    touched.push(write(&day_dir.join("mod.rs"), &day_module(year, day))?);
+    for part in 1..=2 {
+        let path = day_dir.join(format!("part{}.rs", part));
+        touched.push(write(&path, PART_TEMPLATE)?);
+    }
+
+    let lib = year_dir.join("src").join("lib.rs");
+    touched.push(edit(&lib, |text| register_day(text, day))?);
+
+    let input = default_path(&year_dir, year, day);
+    if !input.exists() {
+        touched.push(write(&input, "")?);
+    }
+    Ok(touched)
+}
+
+/// Creates a year crate with no days and adds it to the workspace
+fn new_year(root: &Path, year: u16) -> Result<Vec<PathBuf>> {
+    let year_dir = root.join(year.to_string());
+    let cargo_toml = format!(
+        r#"[package]
+name = "aoc-{year}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc-lib = {{ path = "../aoc-lib" }}
+anyhow = "1.0"
+
+[[bin]]
+name = "main"
+path = "src/main.rs"
+"#
+    );
+    let lib = format!(
+        r#"use aoc_lib::Registry;
+
+/// Returns every solved day of {year}
+pub fn registry() -> Registry {{
+    Registry::new({year})
+}}
+
+#[cfg(test)]
+mod tests {{
+    aoc_lib::aoc_examples!();
+}}
+"#
+    );
+    let main = format!(
+        r#"use anyhow::Result;
+
+fn main() -> Result<()> {{
+    aoc_lib::runner::main(&aoc_{year}::registry())
+}}
+"#
+    );
+
+    Ok(vec![
+        write(&year_dir.join("Cargo.toml"), &cargo_toml)?,
+        write(&year_dir.join("src").join("lib.rs"), &lib)?,
+        write(&year_dir.join("src").join("main.rs"), &main)?,
+        edit(&root.join("Cargo.toml"), |text| add_member(text, year))?,
+        edit(&root.join("src").join("lib.rs"), |text| {
+            add_registry(text, year)
+        })?,
+    ])
+}
+
+fn day_module(year: u16, day: u8) -> String {
+    format!(
+        "pub mod part1;\npub mod part2;\n\naoc_lib::solution!(Day{day}, {year}, {day}, part1::solve, part2::solve);\n"
+    )
+}
+
+const PART_TEMPLATE: &str = r#"use anyhow::{bail, Result};
+use aoc_lib::parse_lines;
+use aoc_lib::runner::Unsolved;
+
+pub fn solve(input: &str) -> Result<u64> {
+    let _lines = parse_lines(input);
+    // reported as unsolved rather than failed until this returns an answer
+    bail!(Unsolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "paste the example from the puzzle"]
+    fn test_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 0);
+    }
+}
+"#;
+
+fn write(path: &Path, contents: &str) -> Result<PathBuf> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    }
+    fs::write(path, contents)
+        .with_context(|| format!("Failed to write file: {}", path.display()))?;
+    Ok(path.to_path_buf())
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Result<String>) -> Result<PathBuf> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read file: {}", path.display()))?;
+    let text = f(&text).with_context(|| format!("Failed to update {}", path.display()))?;
+    write(path, &text)
+}
+
+/// Adds a year crate to the workspace members and the `aoc` dependencies
+fn add_member(cargo_toml: &str, year: u16) -> Result<String> {
+    let text = insert_sorted(
+        cargo_toml,
+        &format!("    \"{}\",", year),
+        year.into(),
+        |line| number_between(line, "    \"", "\","),
+    )
+    .context("No year crates in the workspace members")?;
+    insert_sorted(
+        &text,
+        &format!("aoc-{year} = {{ path = \"./{year}\" }}"),
+        year.into(),
+        |line| number_between(line, "aoc-", " = "),
+    )
+    .context("No year crates in the dependencies")
+}
+
+/// Adds a year's registry to `registries()` in the `aoc` crate
+fn add_registry(lib: &str, year: u16) -> Result<String> {
+    insert_sorted(
+        lib,
+        &format!("        aoc_{}::registry(),", year),
+        year.into(),
+        |line| number_between(line, "        aoc_", "::registry(),"),
+    )
+    .context("No registries in registries()")
+}
+
+/// Declares a day's module in a year crate and registers its solution
+fn register_day(lib: &str, day: u8) -> Result<String> {
+    let text = match insert_sorted(lib, &format!("pub mod day{};", day), day.into(), |line| {
+        number_between(line, "pub mod day", ";")
+    }) {
+        Some(text) => text,
+        None => lib.replacen("\n\n", &format!("\n\npub mod day{};\n\n", day), 1),
+    };
+    let register = format!("        .register::<day{day}::Day{day}>()");
+    if let Some(text) = insert_sorted(&text, &register, day.into(), |line| {
+        number_between(line, "        .register::<day", "::")
+    }) {
+        return Ok(text);
+    }
+    insert_after(&text, &register, |line| {
+        line.trim_start().starts_with("Registry::new(")
+    })
+    .context("No Registry::new(..) in registry()")
+}
+
+/// Parses the number between `prefix`, at the start of `line`, and the next `suffix`
+fn number_between(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    let (number, _) = line.strip_prefix(prefix)?.split_once(suffix)?;
+    number.parse().ok()
+}
+
+/// Inserts `new` among the lines keyed by `key_of`, keeping them in ascending order.
+///
+/// Returns `None` if no line has a key.
+fn insert_sorted(
+    text: &str,
+    new: &str,
+    key: u32,
+    key_of: impl Fn(&str) -> Option<u32>,
+) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| key_of(line).map(|k| (i, k)))
+        .collect();
+    let at = match keyed.iter().find(|&&(_, k)| k > key) {
+        Some(&(i, _)) => i,
+        None => keyed.last()?.0 + 1,
+    };
+    Some(splice(&lines, at, new))
+}
+
+fn insert_after(text: &str, new: &str, is_anchor: impl Fn(&str) -> bool) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let at = lines.iter().position(|line| is_anchor(line))? + 1;
+    Some(splice(&lines, at, new))
+}
+
+fn splice(lines: &[&str], at: usize, new: &str) -> String {
+    let mut lines = lines.to_vec();
+    lines.insert(at, new);
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const ROOT_CARGO: &str = r#"[workspace]
+members = [
+    "aoc-lib",
+    "2015",
+    "2024",
+]
+
+[dependencies]
+aoc-lib = { path = "./aoc-lib" }
+aoc-2015 = { path = "./2015" }
+aoc-2024 = { path = "./2024" }
+anyhow = "1.0"
+"#;
+
+    const ROOT_LIB: &str = r#"pub fn registries() -> Vec<Registry> {
+    vec![
+        aoc_2015::registry(),
+        aoc_2024::registry(),
+    ]
+}
+"#;
+
+    #[test]
+    fn test_add_member() {
+        let text = add_member(ROOT_CARGO, 2016).unwrap();
+        assert!(text.contains("    \"2015\",\n    \"2016\",\n    \"2024\","));
+        assert!(text.contains(
+            "aoc-2015 = { path = \"./2015\" }\naoc-2016 = { path = \"./2016\" }\naoc-2024"
+        ));
+        let text = add_member(ROOT_CARGO, 2025).unwrap();
+        assert!(text.contains(
+            "aoc-2024 = { path = \"./2024\" }\naoc-2025 = { path = \"./2025\" }\nanyhow"
+        ));
+    }
+
+    #[test]
+    fn test_add_registry() {
+        let text = add_registry(ROOT_LIB, 2019).unwrap();
+        assert!(text.contains(
+            "aoc_2015::registry(),\n        aoc_2019::registry(),\n        aoc_2024::registry(),"
+        ));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let lib = "use aoc_lib::Registry;\n\npub mod day1;\npub mod day3;\n\npub fn registry() -> Registry {\n    Registry::new(2015)\n        .register::<day1::Day1>()\n        .register::<day3::Day3>()\n}\n";
+        let text = register_day(lib, 2).unwrap();
+        assert!(text.contains("pub mod day1;\npub mod day2;\npub mod day3;"));
+        assert!(text.contains(
+            ".register::<day1::Day1>()\n        .register::<day2::Day2>()\n        .register::<day3::Day3>()"
+        ));
+        let text = register_day(&text, 10).unwrap();
+        assert!(text.contains("pub mod day3;\npub mod day10;\n"));
+        assert!(text.contains(".register::<day3::Day3>()\n        .register::<day10::Day10>()\n}"));
+    }
+
+    #[test]
+    fn test_new_day_in_new_year() {
+        let _env = crate::env_lock();
+        let root = env::temp_dir().join(format!("aoc-test-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write(&root.join("Cargo.toml"), ROOT_CARGO).unwrap();
+        write(&root.join("src").join("lib.rs"), ROOT_LIB).unwrap();
+
+        new_day(&root, 2017, 3).unwrap();
+        new_day(&root, 2017, 1).unwrap();
+        let lib = fs::read_to_string(root.join("2017/src/lib.rs")).unwrap();
+        assert!(lib.contains("use aoc_lib::Registry;\n\npub mod day1;\npub mod day3;\n\n"));
+        assert!(lib.contains(
+            "Registry::new(2017)\n        .register::<day1::Day1>()\n        .register::<day3::Day3>()\n}"
+        ));
+        assert!(fs::read_to_string(root.join("2017/src/day3/mod.rs"))
+            .unwrap()
+            .contains("solution!(Day3, 2017, 3, part1::solve, part2::solve)"));
+        assert!(fs::read_to_string(root.join("2017/src/day3/part2.rs"))
+            .unwrap()
+            .contains("bail!(Unsolved)"));
+        assert!(root.join("2017/input/day1.in").exists());
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("    \"2015\",\n    \"2017\",\n"));
+        assert!(fs::read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .contains("aoc_2017::registry(),"));
+
+        assert!(new_day(&root, 2017, 3).is_err());
+        fs::create_dir_all(root.join("2023")).unwrap();
+        assert!(new_day(&root, 2023, 1).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+use crate::cache::Cache;
+use crate::registries;
+use anyhow::{Context, Result};
+use aoc_lib::benchmark::Benchmarks;
+use aoc_lib::verify::{Answers, ANSWERS_FILE};
+use aoc_lib::Registry;
+use std::fs;
+use std::path::Path;
+
+/// What is known about one part of a day
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PartStatus {
+    /// A solver is registered for the part
+    pub implemented: bool,
+    /// The input of the day is cached and not empty
+    pub has_input: bool,
+    /// A known answer is recorded for the part
+    pub verified: bool,
+    /// A benchmark is stored for the part
+    pub benchmarked: bool,
+}
+
+impl PartStatus {
+    /// Returns the matrix symbol of the furthest stage the part has reached
+    pub fn symbol(&self) -> char {
+        if !self.implemented {
+            '.'
+        } else if !self.has_input {
+            '!'
+        } else if self.benchmarked {
+            '#'
+        } else if self.verified {
+            '*'
+        } else {
+            'o'
+        }
+    }
+}
+
+/// The status of every part of one year, indexed by day - 1 and part - 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct YearStatus {
+    pub year: u16,
+    pub parts: [[PartStatus; 2]; 25],
+}
+
+impl YearStatus {
+    /// Counts the parts for which `f` holds
+    pub fn count(&self, f: impl Fn(&PartStatus) -> bool) -> usize {
+        self.parts.iter().flatten().filter(|part| f(part)).count()
+    }
+}
+
+/// A year directory found in the workspace
+pub enum Discovered {
+    /// A year crate registered with `aoc`
+    Crate(Registry),
+    /// A year crate `aoc` was built without, e.g. one just scaffolded
+    Unregistered(u16),
+    /// A year directory holding something other than a Rust crate
+    Foreign(u16),
+}
+
+/// Finds the year directories of the workspace, along with every registered year
+pub fn discover(root: &Path) -> Result<Vec<Discovered>> {
+    let mut registries = registries();
+    let mut found = Vec::new();
+    for entry in fs::read_dir(root)
+        .with_context(|| format!("Failed to read directory: {}", root.display()))?
+    {
+        let path = entry?.path();
+        let Some(year) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<u16>().ok())
+            .filter(|year| (2015..=2099).contains(year) && path.is_dir())
+        else {
+            continue;
+        };
+        if let Some(i) = registries.iter().position(|r| r.year() == year) {
+            found.push(Discovered::Crate(registries.remove(i)));
+        } else if path.join("Cargo.toml").exists() {
+            found.push(Discovered::Unregistered(year));
+        } else {
+            found.push(Discovered::Foreign(year));
+        }
+    }
+    found.extend(registries.into_iter().map(Discovered::Crate));
+    found.sort_by_key(|discovered| match discovered {
+        Discovered::Crate(registry) => registry.year(),
+        Discovered::Unregistered(year) | Discovered::Foreign(year) => *year,
+    });
+    Ok(found)
+}
+
+/// Gathers the status of a year's parts from its registry, inputs, answers and benchmarks
+pub fn year_status(
+    cache: &Cache,
+    benchmarks: &Benchmarks,
+    registry: &Registry,
+) -> Result<YearStatus> {
+    let year = registry.year();
+    let answers = Answers::load(&cache.year_dir(year).join(ANSWERS_FILE))?;
+    let missing: Vec<u8> = cache
+        .missing_inputs(registry)
+        .into_iter()
+        .map(|(day, _)| day)
+        .collect();
+
+    let mut status = YearStatus {
+        year,
+        parts: [[PartStatus::default(); 2]; 25],
+    };
+    for (day, part) in registry.parts() {
+        let Some(slot) = status
+            .parts
+            .get_mut(usize::from(day.day) - 1)
+            .and_then(|parts| parts.get_mut(usize::from(part) - 1))
+        else {
+            continue;
+        };
+        *slot = PartStatus {
+            implemented: true,
+            has_input: !missing.contains(&day.day),
+            verified: answers.get(day.day, part).is_some(),
+            benchmarked: benchmarks
+                .get(year, day.day, part)
+                .is_some_and(|record| record.is_verified()),
+        };
+    }
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol() {
+        let part = |implemented, has_input, verified, benchmarked| PartStatus {
+            implemented,
+            has_input,
+            verified,
+            benchmarked,
+        };
+        assert_eq!(part(false, true, true, true).symbol(), '.');
+        assert_eq!(part(true, false, true, true).symbol(), '!');
+        assert_eq!(part(true, true, false, false).symbol(), 'o');
+        assert_eq!(part(true, true, true, false).symbol(), '*');
+        assert_eq!(part(true, true, true, true).symbol(), '#');
+    }
+
+    #[test]
+    fn test_workspace_status() {
+        let root = crate::workspace_dir();
+        let found = discover(&root).unwrap();
+        assert!(found.iter().any(|d| matches!(d, Discovered::Foreign(2023))));
+
+        let registry = crate::registry(2015).unwrap();
+        let benchmarks = Benchmarks::load(&root).unwrap();
+        let status = year_status(&Cache::new(&root), &benchmarks, &registry).unwrap();
+        assert!(status.parts[0][0].implemented);
+        assert!(status.parts[0][0].verified);
+        assert_eq!(status.count(|p| p.implemented), registry.parts().count());
+    }
+}
@@ -0,0 +1,163 @@
+use aoc_lib::benchmark::{format_duration, Benchmarks};
+use aoc_lib::runner::cell;
+
+/// Marks the start of the generated section in a README
+pub const SECTION_START: &str = "<!-- benchmarks:start -->";
+/// Marks the end of the generated section in a README
+pub const SECTION_END: &str = "<!-- benchmarks:end -->";
+
+/// One stored benchmark, as shown in the summary
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    // unknown for legacy benchmarks that may not time this part, see `BenchRecord::is_verified`
+    pub answer: Option<String>,
+    pub mean_ns: f64,
+    pub median_ns: Option<f64>,
+    pub samples: usize,
+    pub git_rev: Option<String>,
+    pub legacy: bool,
+}
+
+/// Returns the stored benchmarks of a year, ordered by day and part
+pub fn rows(benchmarks: &Benchmarks, year: u16) -> Vec<Row> {
+    benchmarks
+        .year(year)
+        .into_iter()
+        .map(|(day, part, record)| Row {
+            day,
+            part,
+            answer: record.answer.as_ref().map(cell),
+            mean_ns: record.stats.mean_ns,
+            median_ns: record.stats.median_ns,
+            samples: record.stats.samples,
+            git_rev: record.git_rev.clone(),
+            legacy: record.legacy,
+        })
+        .collect()
+}
+
+/// Adds up the mean times of the rows whose answer is known
+pub fn total_ns(rows: &[Row]) -> f64 {
+    // folded from 0.0, as an empty float sum is -0.0
+    rows.iter()
+        .filter(|row| row.answer.is_some())
+        .fold(0.0, |total, row| total + row.mean_ns)
+}
+
+/// Returns what a row was timed with, its revision or that it is a legacy record
+pub fn revision(row: &Row) -> &str {
+    match (&row.git_rev, row.legacy) {
+        (Some(rev), _) => rev,
+        (None, true) => "legacy",
+        (None, false) => "",
+    }
+}
+
+/// Formats the rows as a markdown section, between the section markers
+pub fn markdown(year: u16, rows: &[Row]) -> String {
+    let mut text = format!("{}\n## Benchmarks\n\n", SECTION_START);
+    if rows.is_empty() {
+        text.push_str(&format!("No benchmarks of {} are stored yet.\n", year));
+    } else {
+        text.push_str("| Day | Part | Mean | Median | Samples | Revision |\n");
+        text.push_str("| --: | ---: | ---: | -----: | ------: | -------- |\n");
+        for row in rows {
+            // legacy rows of unknown answer may time another day, so they stay out of the total
+            let mean = match row.answer {
+                Some(_) => format_duration(row.mean_ns),
+                None => format!("({})", format_duration(row.mean_ns)),
+            };
+            text.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                row.day,
+                row.part,
+                mean,
+                row.median_ns.map(format_duration).unwrap_or_default(),
+                row.samples,
+                revision(row)
+            ));
+        }
+        text.push_str(&format!(
+            "| **Total** | | **{}** | | | |\n",
+            format_duration(total_ns(rows))
+        ));
+    }
+    text.push_str(&format!(
+        "\nGenerated by `aoc bench {} --readme`.\n{}\n",
+        year, SECTION_END
+    ));
+    text
+}
+
+/// Replaces the generated section of a README, or appends it if there is none yet
+pub fn update_section(readme: &str, section: &str) -> String {
+    if let (Some(start), Some(end)) = (readme.find(SECTION_START), readme.find(SECTION_END)) {
+        if start < end {
+            let after = &readme[end + SECTION_END.len()..];
+            let after = after.strip_prefix('\n').unwrap_or(after);
+            return format!("{}{}{}", &readme[..start], section, after);
+        }
+    }
+    match readme.trim_end() {
+        "" => section.to_string(),
+        text => format!("{}\n\n{}", text, section),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(day: u8, part: u8, mean_ns: f64) -> Row {
+        Row {
+            day,
+            part,
+            answer: Some("74".to_string()),
+            mean_ns,
+            median_ns: Some(mean_ns),
+            samples: 100,
+            git_rev: None,
+            legacy: false,
+        }
+    }
+
+    #[test]
+    fn test_markdown() {
+        let text = markdown(2015, &[row(1, 1, 1500.0), row(1, 2, 2_000_000.0)]);
+        assert!(text.starts_with(SECTION_START));
+        assert!(text.ends_with(&format!("{}\n", SECTION_END)));
+        assert!(text.contains("| 1 | 2 | 2.0ms | 2.0ms | 100 |  |\n"));
+        assert!(text.contains("| **Total** | | **2.0ms** | | | |\n"));
+        assert!(markdown(2016, &[]).contains("No benchmarks of 2016"));
+    }
+
+    #[test]
+    fn test_legacy_rows() {
+        let unknown = Row {
+            answer: None,
+            median_ns: None,
+            legacy: true,
+            ..row(1, 2, 3_000_000.0)
+        };
+        let rows = [row(1, 1, 1_000_000.0), unknown];
+        assert_eq!(total_ns(&rows), 1_000_000.0);
+        assert_eq!(format_duration(total_ns(&rows[1..])), "0.0ns");
+        let text = markdown(2015, &rows);
+        assert!(text.contains("| 1 | 2 | (3.0ms) |  | 100 | legacy |\n"));
+        assert!(text.contains("| **Total** | | **1.0ms** | | | |\n"));
+    }
+
+    #[test]
+    fn test_update_section() {
+        let section = markdown(2015, &[row(1, 1, 1500.0)]);
+        let readme = update_section("# 2015\n", &section);
+        assert_eq!(readme, format!("# 2015\n\n{}", section));
+
+        let newer = markdown(2015, &[row(1, 1, 900.0)]);
+        let updated = update_section(&format!("{}\nNotes\n", readme), &newer);
+        assert_eq!(updated, format!("# 2015\n\n{}\nNotes\n", newer));
+        assert_eq!(update_section("", &newer), newer);
+    }
+}